@@ -1,2 +0,0 @@
-pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY: &str = "new_key";
-pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT: &str = "weight";
@@ -0,0 +1,36 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::runtime;
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{runtime_args, ContractHash, RuntimeArgs};
+use key_change_proposals::constants::{
+    ENTRY_POINT_TAKE_APPROVED_CHANGE, NAMED_KEY_CONTRACT_HASH, RUNTIME_ARG_PROPOSAL_ID,
+};
+use key_manager::operation::KeyOperation;
+use multisig_common::errors::ApplyKeyChangeError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let proposal_id: u64 = runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID);
+    let contract_hash = runtime::get_key(NAMED_KEY_CONTRACT_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(ApplyKeyChangeError::NotInstalled);
+
+    // The contract only hands out a change approved by the signers, and only to this account.
+    // Applying it still needs a deploy meeting the key management threshold, which the host
+    // checks against the keys signing this deploy.
+    let change: KeyOperation = runtime::call_contract(
+        contract_hash,
+        ENTRY_POINT_TAKE_APPROVED_CHANGE,
+        runtime_args! {
+            RUNTIME_ARG_PROPOSAL_ID => proposal_id,
+        },
+    );
+
+    change.apply();
+}
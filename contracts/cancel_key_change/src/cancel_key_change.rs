@@ -0,0 +1,20 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::runtime;
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use multisig_common::errors::CancelKeyChangeError;
+use queue_key_change::constants::RUNTIME_ARG_CHANGE_ID;
+use queue_key_change::queue::take_queued_change;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let change_id: u64 = runtime::get_named_arg(RUNTIME_ARG_CHANGE_ID);
+
+    // Any deploy of the account can cancel, so honest keys meeting the deployment threshold are
+    // enough to stop a change queued by others. A key below that threshold cannot deploy alone.
+    take_queued_change(change_id).unwrap_or_revert_with(CancelKeyChangeError::MissingChange);
+}
@@ -0,0 +1,121 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use casper_contract::contract_api::{account, runtime};
+use casper_types::account::{AccountHash, ActionType, AddKeyFailure, UpdateKeyFailure, Weight};
+use casper_types::Key;
+use configure_multisig::constants::{RUNTIME_ARG_ASSOCIATED_KEYS, RUNTIME_ARG_PRIMARY_KEY_WEIGHT};
+use key_manager::probes::total_key_weight;
+use key_manager::thresholds::set_action_threshold;
+use multisig_audit_log::{record_key_change, record_key_set, record_threshold_change, Operation};
+use multisig_common::constants::{
+    RUNTIME_ARG_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD,
+};
+use multisig_common::errors::ConfigureMultisigError;
+
+/// Sets the weight of `account`, reverting with a `ConfigureMultisigError` if the host refuses it.
+fn update_key(account: AccountHash, weight: u8) {
+    match account::update_associated_key(account, Weight::new(weight)) {
+        Ok(()) => {}
+        // Only the primary key can be missing: listed keys are updated after failing to be added.
+        Err(UpdateKeyFailure::MissingKey) => {
+            runtime::revert(ConfigureMultisigError::MissingPrimaryKey)
+        }
+        Err(UpdateKeyFailure::PermissionDenied) => {
+            runtime::revert(ConfigureMultisigError::PermissionDenied)
+        }
+        Err(UpdateKeyFailure::ThresholdViolation) => {
+            runtime::revert(ConfigureMultisigError::UnreachableThresholds)
+        }
+        Err(failure) => runtime::revert(failure),
+    }
+}
+
+/// Adds `account` with `weight`, or updates its weight if it is already associated.
+fn add_or_update_key(account: AccountHash, weight: u8) {
+    match account::add_associated_key(account, Weight::new(weight)) {
+        Ok(()) => record_key_change(Operation::AddKey, account, Some(weight)),
+        Err(AddKeyFailure::DuplicateKey) => {
+            update_key(account, weight);
+            record_key_change(Operation::UpdateKeyWeight, account, Some(weight));
+        }
+        Err(AddKeyFailure::MaxKeysLimit) => runtime::revert(ConfigureMultisigError::MaxKeysLimit),
+        Err(AddKeyFailure::PermissionDenied) => {
+            runtime::revert(ConfigureMultisigError::PermissionDenied)
+        }
+        Err(failure) => runtime::revert(failure),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let primary_key_weight: u8 = runtime::get_named_arg(RUNTIME_ARG_PRIMARY_KEY_WEIGHT);
+    let associated_keys: BTreeMap<Key, u8> = runtime::get_named_arg(RUNTIME_ARG_ASSOCIATED_KEYS);
+    let deployment_threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD);
+    let key_mgmt_threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD);
+
+    if deployment_threshold > key_mgmt_threshold {
+        runtime::revert(ConfigureMultisigError::InvalidThresholds);
+    }
+
+    // Permissions are checked against the account as it was before this deploy, so the order below
+    // only has to keep the host's weight invariants satisfied at every step. The primary key is
+    // parked at the maximum weight until the end, which keeps any threshold reachable while the
+    // other keys and the thresholds change. Lowering it to its target weight last is the step
+    // that checks the final configuration.
+    let primary_account = runtime::get_caller();
+    update_key(primary_account, u8::MAX);
+
+    // The host sums weights with saturation, so the listed total is computed the same way.
+    let mut listed_weight = primary_key_weight;
+    let mut listed_keys = BTreeMap::new();
+    for (associated_key, weight) in associated_keys {
+        if let Key::Account(associated_account) = associated_key {
+            // The primary key's weight is set through its own argument.
+            if associated_account == primary_account {
+                runtime::revert(ConfigureMultisigError::InvalidAccount);
+            }
+            add_or_update_key(associated_account, weight);
+            listed_weight = listed_weight.saturating_add(weight);
+            listed_keys.insert(associated_account, weight);
+        } else {
+            runtime::revert(ConfigureMultisigError::InvalidAccount);
+        }
+    }
+
+    // Deployment must never exceed key management, so key management goes through the maximum
+    // before both thresholds settle on their target values.
+    set_action_threshold(ActionType::KeyManagement, Weight::new(u8::MAX));
+    set_action_threshold(ActionType::Deployment, Weight::new(deployment_threshold));
+    set_action_threshold(ActionType::KeyManagement, Weight::new(key_mgmt_threshold));
+
+    update_key(primary_account, primary_key_weight);
+
+    // Keys that are not listed cannot be enumerated, let alone removed, but any weight they carry
+    // shows up in the account's total weight. Refuse the configuration rather than leave it
+    // different from the one declared. A listed total of `u8::MAX` hides any extra weight.
+    let all_keys_listed = listed_weight < u8::MAX;
+    if all_keys_listed && total_key_weight(key_mgmt_threshold) > listed_weight {
+        runtime::revert(ConfigureMultisigError::UnlistedKeys);
+    }
+
+    record_key_change(
+        Operation::UpdateKeyWeight,
+        primary_account,
+        Some(primary_key_weight),
+    );
+    record_threshold_change(Some(deployment_threshold), Some(key_mgmt_threshold));
+
+    // The listed keys are then known to be every associated key, which lets the audit log drop
+    // keys recorded earlier and serve the whole configuration.
+    if all_keys_listed {
+        listed_keys.insert(primary_account, primary_key_weight);
+        record_key_set(listed_keys);
+    }
+}
@@ -0,0 +1,2 @@
+pub const RUNTIME_ARG_PRIMARY_KEY_WEIGHT: &str = "primary_weight";
+pub const RUNTIME_ARG_ASSOCIATED_KEYS: &str = "associated_keys";
@@ -0,0 +1,4 @@
+pub const RUNTIME_ARG_DELEGATOR: &str = "delegator";
+pub const RUNTIME_ARG_VALIDATOR: &str = "validator";
+pub const RUNTIME_ARG_NEW_VALIDATOR: &str = "new_validator";
+pub const RUNTIME_ARG_AMOUNT: &str = "amount";
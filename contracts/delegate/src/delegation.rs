@@ -0,0 +1,21 @@
+//! Arguments shared by the `delegate`, `undelegate` and `redelegate` session contracts.
+
+use casper_contract::contract_api::runtime;
+use casper_types::account::AccountHash;
+use casper_types::PublicKey;
+
+use crate::constants::RUNTIME_ARG_DELEGATOR;
+
+/// Reads the public key of the delegator, or `None` if it is not the key of the account running
+/// the session code.
+///
+/// The auction identifies delegators by public key, which session code cannot get from the
+/// account, so the key is an argument checked against the account hash.
+pub fn get_delegator() -> Option<PublicKey> {
+    let delegator: PublicKey = runtime::get_named_arg(RUNTIME_ARG_DELEGATOR);
+    if AccountHash::from(&delegator) == runtime::get_caller() {
+        Some(delegator)
+    } else {
+        None
+    }
+}
@@ -1,4 +1,4 @@
 #![no_std]
 
 pub mod constants;
-pub mod errors;
+pub mod delegation;
@@ -0,0 +1 @@
+pub const NAMED_KEY_FROZEN_THRESHOLDS: &str = "multisig_frozen_thresholds";
@@ -0,0 +1,51 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::{account, runtime, storage};
+use casper_types::account::{ActionType, SetThresholdFailure, Weight};
+use freeze_account::constants::NAMED_KEY_FROZEN_THRESHOLDS;
+use key_manager::probes::check_thresholds;
+use key_manager::thresholds::{revert_with, set_action_threshold};
+use multisig_audit_log::record_threshold_change;
+use multisig_common::constants::{
+    RUNTIME_ARG_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD,
+};
+use multisig_common::errors::FreezeAccountError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    if runtime::has_key(NAMED_KEY_FROZEN_THRESHOLDS) {
+        runtime::revert(FreezeAccountError::AlreadyFrozen);
+    }
+
+    // The current thresholds are supplied by the caller, as the deployment threshold is lost once
+    // raised. They are checked against the account before anything is saved, so a wrong value
+    // cannot be restored on unfreeze.
+    let deployment_threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD);
+    let key_mgmt_threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD);
+    if deployment_threshold > key_mgmt_threshold {
+        runtime::revert(FreezeAccountError::InvalidThreshold);
+    }
+    check_thresholds(
+        deployment_threshold,
+        key_mgmt_threshold,
+        FreezeAccountError::ThresholdMismatch,
+    );
+
+    match account::set_action_threshold(ActionType::KeyManagement, Weight::new(key_mgmt_threshold))
+    {
+        Err(SetThresholdFailure::InsufficientTotalWeight) => {
+            runtime::revert(FreezeAccountError::ThresholdMismatch)
+        }
+        Err(failure) => revert_with(failure),
+        Ok(()) => {}
+    }
+    set_action_threshold(ActionType::Deployment, Weight::new(key_mgmt_threshold));
+    record_threshold_change(Some(key_mgmt_threshold), Some(key_mgmt_threshold));
+
+    let frozen_thresholds = storage::new_uref((deployment_threshold, key_mgmt_threshold));
+    runtime::put_key(NAMED_KEY_FROZEN_THRESHOLDS, frozen_thresholds.into());
+}
@@ -1,4 +1,3 @@
 #![no_std]
 
 pub mod constants;
-pub mod errors;
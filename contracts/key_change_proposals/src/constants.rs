@@ -0,0 +1,24 @@
+pub const ENTRY_POINT_PROPOSE_ADD_KEY: &str = "propose_add_key";
+pub const ENTRY_POINT_PROPOSE_REMOVE_KEY: &str = "propose_remove_key";
+pub const ENTRY_POINT_PROPOSE_UPDATE_KEY_WEIGHT: &str = "propose_update_key_weight";
+pub const ENTRY_POINT_PROPOSE_THRESHOLDS: &str = "propose_thresholds";
+pub const ENTRY_POINT_APPROVE: &str = "approve";
+pub const ENTRY_POINT_REVOKE_APPROVAL: &str = "revoke_approval";
+pub const ENTRY_POINT_TAKE_APPROVED_CHANGE: &str = "take_approved_change";
+
+pub const RUNTIME_ARG_SIGNERS: &str = "signers";
+pub const RUNTIME_ARG_APPROVAL_THRESHOLD: &str = "approval_threshold";
+pub const RUNTIME_ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const RUNTIME_ARG_ACCOUNT: &str = "account";
+pub const RUNTIME_ARG_WEIGHT: &str = "weight";
+
+// Named keys of the installing account.
+pub const NAMED_KEY_CONTRACT_HASH: &str = "key_change_proposals_contract_hash";
+pub const NAMED_KEY_CONTRACT_PACKAGE_HASH: &str = "key_change_proposals_package_hash";
+
+// Named keys of the contract.
+pub const NAMED_KEY_OWNER: &str = "owner";
+pub const NAMED_KEY_SIGNERS: &str = "signers";
+pub const NAMED_KEY_APPROVAL_THRESHOLD: &str = "approval_threshold";
+pub const NAMED_KEY_PROPOSAL_COUNT: &str = "proposal_count";
+pub const DICTIONARY_PROPOSALS: &str = "key_change_proposals";
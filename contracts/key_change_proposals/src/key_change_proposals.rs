@@ -0,0 +1,256 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::contracts::NamedKeys;
+use casper_types::{
+    CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
+};
+use key_change_proposals::constants::{
+    DICTIONARY_PROPOSALS, ENTRY_POINT_APPROVE, ENTRY_POINT_PROPOSE_ADD_KEY,
+    ENTRY_POINT_PROPOSE_REMOVE_KEY, ENTRY_POINT_PROPOSE_THRESHOLDS,
+    ENTRY_POINT_PROPOSE_UPDATE_KEY_WEIGHT, ENTRY_POINT_REVOKE_APPROVAL,
+    ENTRY_POINT_TAKE_APPROVED_CHANGE, NAMED_KEY_APPROVAL_THRESHOLD, NAMED_KEY_CONTRACT_HASH,
+    NAMED_KEY_CONTRACT_PACKAGE_HASH, NAMED_KEY_OWNER, NAMED_KEY_PROPOSAL_COUNT, NAMED_KEY_SIGNERS,
+    RUNTIME_ARG_ACCOUNT, RUNTIME_ARG_APPROVAL_THRESHOLD, RUNTIME_ARG_PROPOSAL_ID,
+    RUNTIME_ARG_SIGNERS, RUNTIME_ARG_WEIGHT,
+};
+use key_change_proposals::proposal::Proposal;
+use key_manager::operation::KeyOperation;
+use multisig_common::constants::{
+    RUNTIME_ARG_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD,
+};
+use multisig_common::errors::KeyChangeProposalsError;
+use multisig_common::proposals::{
+    self, add_proposal, read_named_value, read_proposal, write_proposal,
+};
+use multisig_common::reachability::check_threshold;
+use multisig_common::weight::reachable_weight;
+
+/// Returns the caller, reverting unless it is one of the signers.
+fn get_signer() -> AccountHash {
+    proposals::get_signer::<BTreeMap<AccountHash, u8>, _>(
+        NAMED_KEY_SIGNERS,
+        KeyChangeProposalsError::NotASigner,
+    )
+}
+
+fn get_weight_arg() -> u8 {
+    let weight: u8 = runtime::get_named_arg(RUNTIME_ARG_WEIGHT);
+    // Removal has its own proposal, as in `update_associated_keys`.
+    if weight == 0 {
+        runtime::revert(KeyChangeProposalsError::ZeroWeight);
+    }
+    weight
+}
+
+fn propose(change: KeyOperation) {
+    let proposer = get_signer();
+
+    // Proposing a change counts as approving it.
+    let proposal_id = add_proposal(
+        NAMED_KEY_PROPOSAL_COUNT,
+        Proposal {
+            change,
+            approvals: vec![proposer],
+            applied: false,
+        },
+    );
+
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn propose_add_key() {
+    propose(KeyOperation::Add {
+        account: runtime::get_named_arg(RUNTIME_ARG_ACCOUNT),
+        weight: get_weight_arg(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn propose_remove_key() {
+    propose(KeyOperation::Remove {
+        account: runtime::get_named_arg(RUNTIME_ARG_ACCOUNT),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn propose_update_key_weight() {
+    propose(KeyOperation::UpdateWeight {
+        account: runtime::get_named_arg(RUNTIME_ARG_ACCOUNT),
+        weight: get_weight_arg(),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn propose_thresholds() {
+    let deployment: u8 = runtime::get_named_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD);
+    let key_management: u8 = runtime::get_named_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD);
+    if deployment > key_management {
+        runtime::revert(KeyChangeProposalsError::InvalidThresholds);
+    }
+
+    propose(KeyOperation::SetThresholds {
+        deployment: Some(deployment),
+        key_management: Some(key_management),
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let signer = get_signer();
+    proposals::approve::<Proposal>(signer, runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID));
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_approval() {
+    let signer = get_signer();
+    proposals::revoke_approval::<Proposal>(signer, runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID));
+}
+
+/// Marks an approved proposal as applied and returns its change, for the owner's session code to
+/// apply. A failure to apply reverts the whole deploy, including this mark.
+#[no_mangle]
+pub extern "C" fn take_approved_change() {
+    let owner: AccountHash = read_named_value(NAMED_KEY_OWNER);
+    if runtime::get_caller() != owner {
+        runtime::revert(KeyChangeProposalsError::NotOwner);
+    }
+
+    let proposal_id: u64 = runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID);
+    let mut proposal: Proposal = read_proposal(proposal_id);
+
+    let signers: BTreeMap<AccountHash, u8> = read_named_value(NAMED_KEY_SIGNERS);
+    let approval_threshold: u8 = read_named_value(NAMED_KEY_APPROVAL_THRESHOLD);
+    let approval_weight = reachable_weight(
+        proposal
+            .approvals
+            .iter()
+            .filter_map(|approval| signers.get(approval).copied()),
+    );
+    if approval_weight < u32::from(approval_threshold) {
+        runtime::revert(KeyChangeProposalsError::InsufficientApprovals);
+    }
+
+    let change = proposal.change;
+    proposal.applied = true;
+    write_proposal(proposal_id, proposal);
+
+    runtime::ret(CLValue::from_t(change).unwrap_or_revert());
+}
+
+fn account_entry_point(name: &str, with_weight: bool) -> EntryPoint {
+    let mut params = vec![Parameter::new(RUNTIME_ARG_ACCOUNT, AccountHash::cl_type())];
+    if with_weight {
+        params.push(Parameter::new(RUNTIME_ARG_WEIGHT, CLType::U8));
+    }
+    EntryPoint::new(
+        name,
+        params,
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn proposal_entry_point(name: &str, ret: CLType) -> EntryPoint {
+    EntryPoint::new(
+        name,
+        vec![Parameter::new(RUNTIME_ARG_PROPOSAL_ID, CLType::U64)],
+        ret,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(account_entry_point(ENTRY_POINT_PROPOSE_ADD_KEY, true));
+    entry_points.add_entry_point(account_entry_point(ENTRY_POINT_PROPOSE_REMOVE_KEY, false));
+    entry_points.add_entry_point(account_entry_point(
+        ENTRY_POINT_PROPOSE_UPDATE_KEY_WEIGHT,
+        true,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PROPOSE_THRESHOLDS,
+        vec![
+            Parameter::new(RUNTIME_ARG_DEPLOYMENT_THRESHOLD, CLType::U8),
+            Parameter::new(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD, CLType::U8),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(proposal_entry_point(ENTRY_POINT_APPROVE, CLType::Unit));
+    entry_points.add_entry_point(proposal_entry_point(
+        ENTRY_POINT_REVOKE_APPROVAL,
+        CLType::Unit,
+    ));
+    entry_points.add_entry_point(proposal_entry_point(
+        ENTRY_POINT_TAKE_APPROVED_CHANGE,
+        KeyOperation::cl_type(),
+    ));
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    if runtime::has_key(NAMED_KEY_CONTRACT_HASH) {
+        runtime::revert(KeyChangeProposalsError::AlreadyInstalled);
+    }
+
+    let signers: BTreeMap<AccountHash, u8> = runtime::get_named_arg(RUNTIME_ARG_SIGNERS);
+    let approval_threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_APPROVAL_THRESHOLD);
+
+    if signers.is_empty() || signers.values().any(|weight| *weight == 0) {
+        runtime::revert(KeyChangeProposalsError::InvalidSigners);
+    }
+    if approval_threshold == 0
+        || !check_threshold(signers.values().copied(), approval_threshold).reachable
+    {
+        runtime::revert(KeyChangeProposalsError::InvalidThreshold);
+    }
+
+    // The dictionary is created in the account's context and handed over to the contract, so the
+    // account must not keep its own reference to it.
+    let proposals = storage::new_dictionary(DICTIONARY_PROPOSALS).unwrap_or_revert();
+    runtime::remove_key(DICTIONARY_PROPOSALS);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(NAMED_KEY_OWNER),
+        storage::new_uref(runtime::get_caller()).into(),
+    );
+    named_keys.insert(
+        String::from(NAMED_KEY_SIGNERS),
+        storage::new_uref(signers).into(),
+    );
+    named_keys.insert(
+        String::from(NAMED_KEY_APPROVAL_THRESHOLD),
+        storage::new_uref(approval_threshold).into(),
+    );
+    named_keys.insert(
+        String::from(NAMED_KEY_PROPOSAL_COUNT),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(String::from(DICTIONARY_PROPOSALS), proposals.into());
+
+    let (contract_hash, _) = storage::new_locked_contract(
+        entry_points(),
+        Some(named_keys),
+        Some(String::from(NAMED_KEY_CONTRACT_PACKAGE_HASH)),
+        None,
+    );
+    runtime::put_key(NAMED_KEY_CONTRACT_HASH, contract_hash.into());
+}
@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped};
+use key_manager::operation::KeyOperation;
+use multisig_common::errors::KeyChangeProposalsError;
+use multisig_common::proposals;
+
+use crate::constants::DICTIONARY_PROPOSALS;
+
+/// A key change waiting for the approvals of the signers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    pub change: KeyOperation,
+    /// The signers approving the change, the proposer first.
+    pub approvals: Vec<AccountHash>,
+    pub applied: bool,
+}
+
+/// Typed as a tuple of the fields in order, which serializes to the same bytes.
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        <(KeyOperation, Vec<AccountHash>, bool)>::cl_type()
+    }
+}
+
+impl proposals::Proposal for Proposal {
+    type Error = KeyChangeProposalsError;
+
+    const DICTIONARY: &'static str = DICTIONARY_PROPOSALS;
+    const MISSING_PROPOSAL: KeyChangeProposalsError = KeyChangeProposalsError::MissingProposal;
+    const CLOSED: KeyChangeProposalsError = KeyChangeProposalsError::AlreadyApplied;
+    const ALREADY_APPROVED: KeyChangeProposalsError = KeyChangeProposalsError::AlreadyApproved;
+    const NOT_APPROVED: KeyChangeProposalsError = KeyChangeProposalsError::NotApproved;
+
+    fn approvals(&mut self) -> &mut Vec<AccountHash> {
+        &mut self.approvals
+    }
+
+    fn is_closed(&self) -> bool {
+        self.applied
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.change.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.applied.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.change.serialized_length()
+            + self.approvals.serialized_length()
+            + self.applied.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (change, remainder) = KeyOperation::from_bytes(bytes)?;
+        let (approvals, remainder) = Vec::<AccountHash>::from_bytes(remainder)?;
+        let (applied, remainder) = bool::from_bytes(remainder)?;
+        let proposal = Proposal {
+            change,
+            approvals,
+            applied,
+        };
+        Ok((proposal, remainder))
+    }
+}
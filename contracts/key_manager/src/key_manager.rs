@@ -0,0 +1,43 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_contract::contract_api::runtime;
+use key_manager::operation::KeyOperation;
+use key_manager::session;
+use multisig_common::args::has_named_arg;
+use multisig_common::constants::{RUNTIME_ARG_OPERATION, RUNTIME_ARG_OPERATIONS};
+use multisig_common::errors::KeyManagerError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    match (
+        has_named_arg(RUNTIME_ARG_OPERATION),
+        has_named_arg(RUNTIME_ARG_OPERATIONS),
+    ) {
+        (true, false) => {
+            let operation: String = runtime::get_named_arg(RUNTIME_ARG_OPERATION);
+            session::run(&operation);
+        }
+        (false, true) => {
+            let operations: Vec<KeyOperation> = runtime::get_named_arg(RUNTIME_ARG_OPERATIONS);
+            if operations.is_empty() {
+                runtime::revert(KeyManagerError::EmptyOperations);
+            }
+
+            // Operations run in the given order and any failure reverts the whole deploy, so the
+            // list is applied in full or not at all.
+            for operation in operations {
+                operation.apply();
+            }
+        }
+        (true, true) => runtime::revert(KeyManagerError::ConflictingOperations),
+        (false, false) => runtime::revert(KeyManagerError::MissingOperation),
+    }
+}
@@ -0,0 +1,131 @@
+//! Associated key updates shared by the key management operations.
+//!
+//! Each function reverts with the user error of the session code that historically performed the
+//! update, so a failure reports the same code whether it came through `key_manager` or through
+//! one of the single-purpose contracts.
+
+use casper_contract::contract_api::{account, runtime};
+use casper_types::account::{
+    AccountHash, AddKeyFailure, RemoveKeyFailure, UpdateKeyFailure, Weight,
+};
+use multisig_audit_log::{record_key_change, recorded_key_weight, Operation};
+use multisig_common::errors::{
+    AddAccountError, RemoveAccountError, RotateKeyError, UpdateAssociatedKeysError,
+};
+use multisig_key_expiries::{clear_expiry, move_expiry};
+use multisig_key_labels::{move_label, remove_label};
+
+use crate::probes::recorded_configuration_confirmed;
+
+/// Associates `account` with the account running the deploy.
+pub fn add_key(account: AccountHash, weight: u8) {
+    match account::add_associated_key(account, Weight::new(weight)) {
+        Ok(()) => {
+            record_key_change(Operation::AddKey, account, Some(weight));
+            // Drop an expiry left behind by a removal outside these contracts, so a key added
+            // without one never expires.
+            clear_expiry(account);
+        }
+        Err(AddKeyFailure::DuplicateKey) => runtime::revert(AddAccountError::DuplicateKey),
+        Err(AddKeyFailure::MaxKeysLimit) => runtime::revert(AddAccountError::MaxKeysLimit),
+        Err(AddKeyFailure::PermissionDenied) => runtime::revert(AddAccountError::PermissionDenied),
+        Err(failure) => runtime::revert(failure),
+    }
+}
+
+/// Removes `account` from the associated keys, together with its label and expiry, returning the
+/// host's failure instead of reverting so the caller can report it with its own user errors.
+pub fn try_remove_key(account: AccountHash) -> Result<(), RemoveKeyFailure> {
+    account::remove_associated_key(account)?;
+    record_key_change(Operation::RemoveKey, account, None);
+    remove_label(account);
+    clear_expiry(account);
+    Ok(())
+}
+
+/// Removes `account` from the associated keys, together with its label and expiry.
+pub fn remove_key(account: AccountHash) {
+    match try_remove_key(account) {
+        Ok(()) => {}
+        Err(RemoveKeyFailure::MissingKey) => runtime::revert(RemoveAccountError::MissingKey),
+        Err(RemoveKeyFailure::PermissionDenied) => {
+            runtime::revert(RemoveAccountError::PermissionDenied)
+        }
+        // Session code cannot read the account's thresholds, but the host refuses the removal
+        // with `ThresholdViolation` when the remaining keys could no longer reach the
+        // deployment or key management threshold.
+        Err(RemoveKeyFailure::ThresholdViolation) => {
+            runtime::revert(RemoveAccountError::LockoutRisk)
+        }
+        Err(failure) => runtime::revert(failure),
+    }
+}
+
+/// Changes the weight of the associated key `account`.
+pub fn update_key_weight(account: AccountHash, weight: u8) {
+    // A key with weight zero stays associated while contributing nothing, so removal has to go
+    // through the remove operation where it is explicit.
+    if weight == 0 {
+        runtime::revert(UpdateAssociatedKeysError::ZeroWeight);
+    }
+    // Session code cannot read the current weight, so a no-op is detected against the audit log,
+    // once the host confirms it. Other session code may have changed the key without recording it.
+    if recorded_key_weight(account) == Some(weight)
+        && recorded_configuration_confirmed(UpdateAssociatedKeysError::UnrecordedThresholds)
+    {
+        runtime::revert(UpdateAssociatedKeysError::NoChange);
+    }
+
+    match account::update_associated_key(account, Weight::new(weight)) {
+        Ok(()) => record_key_change(Operation::UpdateKeyWeight, account, Some(weight)),
+        Err(UpdateKeyFailure::MissingKey) => runtime::revert(UpdateAssociatedKeysError::MissingKey),
+        Err(UpdateKeyFailure::PermissionDenied) => {
+            runtime::revert(UpdateAssociatedKeysError::PermissionDenied)
+        }
+        // The host refuses to lower a weight when the total weight of all keys would then fall
+        // below the deployment or key management threshold.
+        Err(UpdateKeyFailure::ThresholdViolation) => {
+            runtime::revert(UpdateAssociatedKeysError::LockoutRisk)
+        }
+        Err(failure) => runtime::revert(failure),
+    }
+}
+
+/// Replaces the associated key `old_account` with `new_account`, which takes over its weight.
+///
+/// Session code cannot read associated key weights, so the weight is the one the audit log records
+/// for the old key. `weight` is only needed for a key whose weight the audit log does not know, and
+/// must match the recorded weight otherwise. The new key is added before the old one is removed,
+/// leaving the old key's weight untouched until it leaves the account.
+pub fn rotate_key(old_account: AccountHash, new_account: AccountHash, weight: Option<u8>) {
+    // A key with weight zero contributes nothing, so rotating to one would silently drop the old
+    // key's weight from the account.
+    if weight == Some(0) {
+        runtime::revert(RotateKeyError::ZeroWeight);
+    }
+    let weight = match (recorded_key_weight(old_account), weight) {
+        (Some(recorded_weight), Some(weight)) if weight != recorded_weight => {
+            runtime::revert(RotateKeyError::WeightMismatch)
+        }
+        (Some(recorded_weight), _) => recorded_weight,
+        (None, Some(weight)) => weight,
+        (None, None) => runtime::revert(RotateKeyError::UnknownWeight),
+    };
+
+    match account::add_associated_key(new_account, Weight::new(weight)) {
+        Ok(()) => record_key_change(Operation::AddKey, new_account, Some(weight)),
+        Err(AddKeyFailure::DuplicateKey) => runtime::revert(RotateKeyError::DuplicateKey),
+        Err(failure) => runtime::revert(failure),
+    }
+
+    match account::remove_associated_key(old_account) {
+        Ok(()) => record_key_change(Operation::RemoveKey, old_account, None),
+        Err(RemoveKeyFailure::MissingKey) => runtime::revert(RotateKeyError::MissingKey),
+        Err(RemoveKeyFailure::ThresholdViolation) => runtime::revert(RotateKeyError::LockoutRisk),
+        Err(failure) => runtime::revert(failure),
+    }
+
+    // The new key keeps the label and the expiry of the key it replaces.
+    move_label(old_account, new_account);
+    move_expiry(old_account, new_account);
+}
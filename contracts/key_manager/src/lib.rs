@@ -0,0 +1,9 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod keys;
+pub mod operation;
+pub mod probes;
+pub mod session;
+pub mod thresholds;
@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped};
+
+use crate::keys::{add_key, remove_key, rotate_key, update_key_weight};
+use crate::thresholds::update_thresholds;
+
+const ADD_TAG: u8 = 0;
+const REMOVE_TAG: u8 = 1;
+const UPDATE_WEIGHT_TAG: u8 = 2;
+const SET_THRESHOLDS_TAG: u8 = 3;
+const ROTATE_TAG: u8 = 4;
+
+/// One step of a list of operations passed to `key_manager`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyOperation {
+    Add {
+        account: AccountHash,
+        weight: u8,
+    },
+    Remove {
+        account: AccountHash,
+    },
+    UpdateWeight {
+        account: AccountHash,
+        weight: u8,
+    },
+    /// `None` leaves that threshold untouched.
+    SetThresholds {
+        deployment: Option<u8>,
+        key_management: Option<u8>,
+    },
+    /// The new key takes the old key's recorded weight. `weight` is only needed for a key whose
+    /// weight the audit log does not record.
+    Rotate {
+        old_account: AccountHash,
+        new_account: AccountHash,
+        weight: Option<u8>,
+    },
+}
+
+impl KeyOperation {
+    /// Applies the operation to the account running the deploy, reverting on failure.
+    pub fn apply(self) {
+        match self {
+            KeyOperation::Add { account, weight } => add_key(account, weight),
+            KeyOperation::Remove { account } => remove_key(account),
+            KeyOperation::UpdateWeight { account, weight } => update_key_weight(account, weight),
+            KeyOperation::SetThresholds {
+                deployment,
+                key_management,
+            } => update_thresholds(deployment, key_management),
+            KeyOperation::Rotate {
+                old_account,
+                new_account,
+                weight,
+            } => rotate_key(old_account, new_account, weight),
+        }
+    }
+}
+
+/// The serialized form of a [`KeyOperation`]: the tag, the accounts and the weights or thresholds.
+///
+/// Each operation fills the fields it needs in order, `Add` for instance being
+/// `(0, (Some(account), None), (Some(weight), None))`, so a client can decode the typed `CLValue`
+/// without this crate. `SetThresholds` puts the deployment threshold first.
+type Repr = (
+    u8,
+    (Option<AccountHash>, Option<AccountHash>),
+    (Option<u8>, Option<u8>),
+);
+
+impl KeyOperation {
+    fn to_repr(self) -> Repr {
+        match self {
+            KeyOperation::Add { account, weight } => {
+                (ADD_TAG, (Some(account), None), (Some(weight), None))
+            }
+            KeyOperation::Remove { account } => (REMOVE_TAG, (Some(account), None), (None, None)),
+            KeyOperation::UpdateWeight { account, weight } => (
+                UPDATE_WEIGHT_TAG,
+                (Some(account), None),
+                (Some(weight), None),
+            ),
+            KeyOperation::SetThresholds {
+                deployment,
+                key_management,
+            } => (
+                SET_THRESHOLDS_TAG,
+                (None, None),
+                (deployment, key_management),
+            ),
+            KeyOperation::Rotate {
+                old_account,
+                new_account,
+                weight,
+            } => (
+                ROTATE_TAG,
+                (Some(old_account), Some(new_account)),
+                (weight, None),
+            ),
+        }
+    }
+
+    fn from_repr(repr: Repr) -> Result<Self, bytesrepr::Error> {
+        let operation = match repr {
+            (ADD_TAG, (Some(account), None), (Some(weight), None)) => {
+                KeyOperation::Add { account, weight }
+            }
+            (REMOVE_TAG, (Some(account), None), (None, None)) => KeyOperation::Remove { account },
+            (UPDATE_WEIGHT_TAG, (Some(account), None), (Some(weight), None)) => {
+                KeyOperation::UpdateWeight { account, weight }
+            }
+            (SET_THRESHOLDS_TAG, (None, None), (deployment, key_management)) => {
+                KeyOperation::SetThresholds {
+                    deployment,
+                    key_management,
+                }
+            }
+            (ROTATE_TAG, (Some(old_account), Some(new_account)), (weight, None)) => {
+                KeyOperation::Rotate {
+                    old_account,
+                    new_account,
+                    weight,
+                }
+            }
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok(operation)
+    }
+}
+
+impl CLTyped for KeyOperation {
+    fn cl_type() -> CLType {
+        Repr::cl_type()
+    }
+}
+
+impl ToBytes for KeyOperation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.to_repr().to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.to_repr().serialized_length()
+    }
+}
+
+impl FromBytes for KeyOperation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (repr, remainder) = Repr::from_bytes(bytes)?;
+        Ok((KeyOperation::from_repr(repr)?, remainder))
+    }
+}
@@ -0,0 +1,152 @@
+//! Checks of the configuration recorded by the audit log against the account.
+//!
+//! Session code cannot read the associated keys or the action thresholds, but the host refuses a
+//! threshold it cannot honour without touching the account, and the reason it gives depends on
+//! both thresholds and on the total weight of the keys.
+
+use alloc::collections::BTreeMap;
+use casper_contract::contract_api::{account, runtime};
+use casper_types::account::{AccountHash, ActionType, SetThresholdFailure, Weight};
+use casper_types::ApiError;
+use multisig_audit_log::recorded_configuration;
+use multisig_common::weight::reachable_weight;
+
+use crate::thresholds::{revert_with, set_action_threshold};
+
+/// Returns the total weight of `keys`, saturated at `u8::MAX` like the host's sum.
+pub fn saturated_weight(keys: &BTreeMap<AccountHash, u8>) -> u8 {
+    reachable_weight(keys.values().copied()).min(u32::from(u8::MAX)) as u8
+}
+
+/// Returns the total weight of all associated keys, saturated at `u8::MAX` like the host's sum.
+///
+/// The host refuses a key management threshold above the total weight without touching the
+/// account, so this binary searches for the highest value it accepts above `key_mgmt_threshold`,
+/// which must itself be accepted, then puts the threshold back to it.
+pub fn total_key_weight(key_mgmt_threshold: u8) -> u8 {
+    let (mut accepted, mut refused) = (u16::from(key_mgmt_threshold), u16::from(u8::MAX) + 1);
+    while refused - accepted > 1 {
+        let probe = (accepted + refused) / 2;
+        match account::set_action_threshold(ActionType::KeyManagement, Weight::new(probe as u8)) {
+            Ok(()) => accepted = probe,
+            Err(SetThresholdFailure::InsufficientTotalWeight) => refused = probe,
+            Err(failure) => revert_with(failure),
+        }
+    }
+    set_action_threshold(ActionType::KeyManagement, Weight::new(key_mgmt_threshold));
+    accepted as u8
+}
+
+/// Tries to set a threshold the host is expected to refuse, reverting with `mismatch` if it
+/// accepts it.
+fn expect_refused<E: Into<ApiError>>(action_type: ActionType, threshold: u8, mismatch: E) {
+    match account::set_action_threshold(action_type, Weight::new(threshold)) {
+        Ok(()) => runtime::revert(mismatch),
+        Err(SetThresholdFailure::DeploymentThreshold)
+        | Err(SetThresholdFailure::KeyManagementThreshold)
+        | Err(SetThresholdFailure::InsufficientTotalWeight) => {}
+        Err(failure) => revert_with(failure),
+    }
+}
+
+/// Checks that the account's deployment threshold is `deployment_threshold` and that its key
+/// management threshold is at most `key_mgmt_threshold`, reverting with `mismatch` otherwise.
+///
+/// Key management is left at `deployment_threshold`, and is restored by the caller.
+pub fn check_thresholds<E: Into<ApiError> + Copy>(
+    deployment_threshold: u8,
+    key_mgmt_threshold: u8,
+    mismatch: E,
+) {
+    // The host refuses a key management threshold below deployment and a deployment threshold
+    // above key management. The first two probes are refused for matching values, so they change
+    // nothing.
+    if let Some(below) = deployment_threshold.checked_sub(1) {
+        expect_refused(ActionType::KeyManagement, below, mismatch);
+    }
+    if let Some(above) = key_mgmt_threshold.checked_add(1) {
+        expect_refused(ActionType::Deployment, above, mismatch);
+    }
+    // Accepting key management at the supplied deployment threshold proves the deployment
+    // threshold is not above it.
+    match account::set_action_threshold(
+        ActionType::KeyManagement,
+        Weight::new(deployment_threshold),
+    ) {
+        Ok(()) => {}
+        Err(SetThresholdFailure::KeyManagementThreshold)
+        | Err(SetThresholdFailure::InsufficientTotalWeight) => runtime::revert(mismatch),
+        Err(failure) => revert_with(failure),
+    }
+}
+
+/// Asks the host to set a threshold that matches the recorded configuration only if refused with
+/// `expected`, reverting with `error` if it is accepted, as the value it replaced is then unknown.
+fn refused_with<E: Into<ApiError>>(
+    action_type: ActionType,
+    threshold: u8,
+    expected: SetThresholdFailure,
+    error: E,
+) -> bool {
+    match account::set_action_threshold(action_type, Weight::new(threshold)) {
+        Ok(()) => runtime::revert(error),
+        Err(failure) => failure == expected,
+    }
+}
+
+/// Returns whether the audit log holds a complete configuration matching the account as far as
+/// the host can tell without changing it: the same total key weight, a key management threshold
+/// not above the recorded one and a deployment threshold not below it.
+///
+/// The probes are thresholds the host refuses for a matching account, so they never change it, but
+/// they need the recorded total weight to exceed the recorded key management threshold and `false`
+/// is returned otherwise. A deploy that does not meet the key management threshold gets `false` as
+/// well. If a threshold was changed by session code that does not record it, the host may accept
+/// a probe, losing the value it replaced, and the deploy reverts with `error`.
+pub fn recorded_configuration_confirmed<E: Into<ApiError> + Copy>(error: E) -> bool {
+    let (keys, deployment_threshold, key_mgmt_threshold) = match recorded_configuration() {
+        Some(configuration) => configuration,
+        None => return false,
+    };
+    let total_weight = saturated_weight(&keys);
+    if total_weight <= key_mgmt_threshold {
+        return false;
+    }
+
+    // Key management below deployment is refused within the total weight, as is deployment above
+    // key management. Above the total weight, the host reports the missing weight instead.
+    let deployment_not_below = match deployment_threshold.checked_sub(1) {
+        Some(below) => refused_with(
+            ActionType::KeyManagement,
+            below,
+            SetThresholdFailure::KeyManagementThreshold,
+            error,
+        ),
+        None => true,
+    };
+    let key_mgmt_not_above = deployment_not_below
+        && refused_with(
+            ActionType::Deployment,
+            key_mgmt_threshold + 1,
+            SetThresholdFailure::DeploymentThreshold,
+            error,
+        );
+    let total_not_below = key_mgmt_not_above
+        && refused_with(
+            ActionType::Deployment,
+            total_weight,
+            SetThresholdFailure::DeploymentThreshold,
+            error,
+        );
+    total_not_below
+        && match total_weight.checked_add(1) {
+            Some(above) => refused_with(
+                ActionType::Deployment,
+                above,
+                SetThresholdFailure::InsufficientTotalWeight,
+                error,
+            ),
+            // A saturated total hides any weight above it.
+            None => true,
+        }
+}
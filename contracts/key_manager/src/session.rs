@@ -0,0 +1,147 @@
+//! Key management operations that read their input from the deploy's named arguments.
+//!
+//! `key_manager` dispatches its `operation` argument to these functions, and the single-purpose
+//! session contracts call the one they are named after.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use casper_contract::contract_api::runtime;
+use casper_types::Key;
+use multisig_common::errors::{
+    AddAccountError, KeyManagerError, RemoveAccountError, RotateKeyError, UpdateAssociatedKeysError,
+};
+use multisig_key_expiries::set_expiry;
+use multisig_key_labels::{is_valid_label, set_label};
+
+use crate::keys::{add_key, remove_key, rotate_key, update_key_weight};
+use crate::thresholds::update_thresholds;
+use multisig_common::args::{
+    get_account_arg, get_optional_u8_arg, has_named_arg, key_to_account_hash,
+};
+use multisig_common::constants::{
+    OPERATION_ADD, OPERATION_REMOVE, OPERATION_ROTATE, OPERATION_SET_THRESHOLDS,
+    OPERATION_UPDATE_WEIGHT, RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH, RUNTIME_ARG_ASSOCIATED_KEY,
+    RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT, RUNTIME_ARG_ASSOCIATED_PUBLIC_KEY,
+    RUNTIME_ARG_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD,
+    RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH, RUNTIME_ARG_NEW_ASSOCIATED_KEY,
+    RUNTIME_ARG_NEW_ASSOCIATED_KEYS, RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY,
+    RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL, RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT,
+    RUNTIME_ARG_NEW_ASSOCIATED_PUBLIC_KEY, RUNTIME_ARG_NEW_KEY_WEIGHT,
+    RUNTIME_ARG_OLD_ASSOCIATED_KEY, RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH,
+    RUNTIME_ARG_REMOVE_ASSOCIATED_KEY, RUNTIME_ARG_REMOVE_ASSOCIATED_PUBLIC_KEY,
+    RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY,
+};
+
+/// Runs the operation named by `operation`.
+pub fn run(operation: &str) {
+    match operation {
+        OPERATION_ADD => add(),
+        OPERATION_REMOVE => remove(),
+        OPERATION_UPDATE_WEIGHT => update_weight(),
+        OPERATION_SET_THRESHOLDS => set_thresholds(),
+        OPERATION_ROTATE => rotate(),
+        _ => runtime::revert(KeyManagerError::UnknownOperation),
+    }
+}
+
+/// Adds a single key, with an optional label and expiry, or a batch of keys.
+pub fn add() {
+    // A failed add reverts the whole deploy, so a batch is either applied in full or not at all.
+    if has_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEYS) {
+        let new_associated_keys: BTreeMap<Key, u8> =
+            runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEYS);
+
+        if new_associated_keys.is_empty() {
+            runtime::revert(AddAccountError::EmptyBatch);
+        }
+
+        for (new_associated_key, new_key_weight) in new_associated_keys {
+            let account = key_to_account_hash(new_associated_key, AddAccountError::InvalidAccount);
+            add_key(account, new_key_weight);
+        }
+        return;
+    }
+
+    let account = get_account_arg(
+        RUNTIME_ARG_NEW_ASSOCIATED_PUBLIC_KEY,
+        RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH,
+        RUNTIME_ARG_NEW_ASSOCIATED_KEY,
+        AddAccountError::InvalidAccount,
+    );
+    let new_key_weight: u8 = runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT);
+
+    // Labels and expiries are only taken for a single key, a batch is added unlabelled and never
+    // expires.
+    let label = if has_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL) {
+        let label: String = runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL);
+        if !is_valid_label(&label) {
+            runtime::revert(AddAccountError::InvalidLabel);
+        }
+        Some(label)
+    } else {
+        None
+    };
+
+    let expires_at = if has_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY) {
+        let expires_at: u64 = runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY);
+        if expires_at <= runtime::get_blocktime().into() {
+            runtime::revert(AddAccountError::InvalidExpiry);
+        }
+        Some(expires_at)
+    } else {
+        None
+    };
+
+    add_key(account, new_key_weight);
+
+    if let Some(label) = label {
+        set_label(account, label);
+    }
+    if let Some(expires_at) = expires_at {
+        set_expiry(account, expires_at);
+    }
+}
+
+/// Removes a single key.
+pub fn remove() {
+    let account = get_account_arg(
+        RUNTIME_ARG_REMOVE_ASSOCIATED_PUBLIC_KEY,
+        RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH,
+        RUNTIME_ARG_REMOVE_ASSOCIATED_KEY,
+        RemoveAccountError::InvalidAccount,
+    );
+    remove_key(account);
+}
+
+/// Changes the weight of a single key.
+pub fn update_weight() {
+    let account = get_account_arg(
+        RUNTIME_ARG_ASSOCIATED_PUBLIC_KEY,
+        RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH,
+        RUNTIME_ARG_ASSOCIATED_KEY,
+        UpdateAssociatedKeysError::InvalidAccount,
+    );
+    let new_weight: u8 = runtime::get_named_arg(RUNTIME_ARG_NEW_KEY_WEIGHT);
+    update_key_weight(account, new_weight);
+}
+
+/// Sets either or both action thresholds.
+pub fn set_thresholds() {
+    // A missing argument leaves that threshold untouched.
+    update_thresholds(
+        get_optional_u8_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD),
+        get_optional_u8_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD),
+    );
+}
+
+/// Replaces one key with another of the same weight.
+pub fn rotate() {
+    let old_associated_key: Key = runtime::get_named_arg(RUNTIME_ARG_OLD_ASSOCIATED_KEY);
+    let new_associated_key: Key = runtime::get_named_arg(RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY);
+    // Only needed for a key whose weight the audit log does not record.
+    let weight = get_optional_u8_arg(RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT);
+
+    let old_account = key_to_account_hash(old_associated_key, RotateKeyError::InvalidAccount);
+    let new_account = key_to_account_hash(new_associated_key, RotateKeyError::InvalidAccount);
+    rotate_key(old_account, new_account, weight);
+}
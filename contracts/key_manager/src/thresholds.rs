@@ -0,0 +1,83 @@
+//! Threshold updates shared by `key_manager` and the contracts that change thresholds.
+
+use casper_contract::contract_api::{account, runtime};
+use casper_types::account::{ActionType, SetThresholdFailure, Weight};
+use multisig_audit_log::{record_threshold_change, recorded_thresholds};
+use multisig_common::errors::UpdateThresholdsError;
+
+use crate::probes::recorded_configuration_confirmed;
+
+/// Reverts with the user error matching a failed threshold update.
+pub fn revert_with(failure: SetThresholdFailure) -> ! {
+    match failure {
+        SetThresholdFailure::KeyManagementThreshold | SetThresholdFailure::DeploymentThreshold => {
+            runtime::revert(UpdateThresholdsError::InvalidThresholds)
+        }
+        SetThresholdFailure::InsufficientTotalWeight => {
+            runtime::revert(UpdateThresholdsError::UnreachableThreshold)
+        }
+        SetThresholdFailure::PermissionDeniedError => {
+            runtime::revert(UpdateThresholdsError::PermissionDenied)
+        }
+        failure => runtime::revert(failure),
+    }
+}
+
+/// Sets a single action threshold, reverting if the host refuses it.
+pub fn set_action_threshold(action_type: ActionType, threshold: Weight) {
+    if let Err(failure) = account::set_action_threshold(action_type, threshold) {
+        revert_with(failure);
+    }
+}
+
+/// Sets both action thresholds, whatever their current values are.
+pub fn set_thresholds(deployment_threshold: Weight, key_mgmt_threshold: Weight) {
+    if deployment_threshold > key_mgmt_threshold {
+        runtime::revert(UpdateThresholdsError::InvalidThresholds);
+    }
+
+    // The host requires deployment <= key management after every single update. Session code
+    // cannot read the current thresholds, so try key management first: it only fails when the
+    // new value is below the current deployment threshold, in which case the account is left
+    // untouched and lowering deployment first is the order that succeeds.
+    match account::set_action_threshold(ActionType::KeyManagement, key_mgmt_threshold) {
+        Err(SetThresholdFailure::KeyManagementThreshold) => {
+            set_action_threshold(ActionType::Deployment, deployment_threshold);
+            set_action_threshold(ActionType::KeyManagement, key_mgmt_threshold);
+        }
+        Ok(()) => set_action_threshold(ActionType::Deployment, deployment_threshold),
+        Err(failure) => revert_with(failure),
+    }
+}
+
+/// Sets whichever thresholds are given and records the change, leaving a missing one untouched.
+pub fn update_thresholds(deployment_threshold: Option<u8>, key_mgmt_threshold: Option<u8>) {
+    // Session code cannot read the current thresholds, so a no-op is detected against the audit
+    // log, once the host confirms it: every supplied threshold matching its last recorded value
+    // would change nothing.
+    let (recorded_deployment, recorded_key_mgmt) = recorded_thresholds();
+    let unchanged = |new: Option<u8>, recorded: Option<u8>| new.is_none() || new == recorded;
+    if (deployment_threshold.is_some() || key_mgmt_threshold.is_some())
+        && unchanged(deployment_threshold, recorded_deployment)
+        && unchanged(key_mgmt_threshold, recorded_key_mgmt)
+        && recorded_configuration_confirmed(UpdateThresholdsError::UnrecordedThresholds)
+    {
+        runtime::revert(UpdateThresholdsError::NoChange);
+    }
+
+    match (deployment_threshold, key_mgmt_threshold) {
+        (Some(deployment_threshold), Some(key_mgmt_threshold)) => set_thresholds(
+            Weight::new(deployment_threshold),
+            Weight::new(key_mgmt_threshold),
+        ),
+        (Some(deployment_threshold), None) => {
+            set_action_threshold(ActionType::Deployment, Weight::new(deployment_threshold))
+        }
+        (None, Some(key_mgmt_threshold)) => {
+            set_action_threshold(ActionType::KeyManagement, Weight::new(key_mgmt_threshold))
+        }
+        (None, None) => runtime::revert(UpdateThresholdsError::MissingThresholds),
+    }
+
+    record_threshold_change(deployment_threshold, key_mgmt_threshold);
+}
@@ -0,0 +1,187 @@
+//! Audit log of the key-management changes made by the multi-signature session contracts.
+//!
+//! Records are appended to the `multisig_audit_log` dictionary, owned by the account under the
+//! named key of the same name. The dictionary holds:
+//!
+//! | Item key                   | Value                                          |
+//! |----------------------------|------------------------------------------------|
+//! | `length`                   | `u64`, the number of records                   |
+//! | `0`, `1`, ...              | [`AuditRecord`], in the order of the changes   |
+//! | `account-hash-...`         | `Option<u8>`, the last recorded key weight     |
+//! | `keys`                     | `BTreeMap<AccountHash, u8>`, the recorded keys |
+//! | `complete`                 | `bool`, whether `keys` lists every key         |
+//! | `deployment_threshold`     | `u8`, the last recorded threshold              |
+//! | `key_management_threshold` | `u8`, the last recorded threshold              |
+//!
+//! Session code cannot read associated keys or thresholds, so the old value of a record is the
+//! new value of the previous record for the same key or threshold. Changes made by other session
+//! code are not seen.
+//!
+//! `keys` only lists every associated key once `configure_multisig.wasm` has declared the whole
+//! configuration, which [`record_key_set`] marks as `complete`.
+#![no_std]
+
+extern crate alloc;
+
+mod record;
+
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::{bytesrepr::FromBytes, CLTyped, URef};
+
+pub use record::{AuditRecord, Operation, ValueChange};
+
+pub const DICTIONARY_AUDIT_LOG: &str = "multisig_audit_log";
+pub const AUDIT_LOG_LENGTH: &str = "length";
+pub const AUDIT_LOG_DEPLOYMENT_THRESHOLD: &str = "deployment_threshold";
+pub const AUDIT_LOG_KEY_MANAGEMENT_THRESHOLD: &str = "key_management_threshold";
+pub const AUDIT_LOG_KEYS: &str = "keys";
+pub const AUDIT_LOG_COMPLETE: &str = "complete";
+
+fn existing_audit_log() -> Option<URef> {
+    runtime::get_key(DICTIONARY_AUDIT_LOG).map(|key| key.into_uref().unwrap_or_revert())
+}
+
+fn audit_log() -> URef {
+    existing_audit_log()
+        .unwrap_or_else(|| storage::new_dictionary(DICTIONARY_AUDIT_LOG).unwrap_or_revert())
+}
+
+fn read<T: CLTyped + FromBytes>(audit_log: URef, item_key: &str) -> Option<T> {
+    storage::dictionary_get(audit_log, item_key).unwrap_or_revert()
+}
+
+/// Returns the last recorded weight of `account_hash`, `None` if it is not recorded as associated.
+pub fn recorded_key_weight(account_hash: AccountHash) -> Option<u8> {
+    let audit_log = existing_audit_log()?;
+    read::<Option<u8>>(audit_log, &account_hash.to_formatted_string()).flatten()
+}
+
+/// Returns the last recorded deployment and key management thresholds.
+pub fn recorded_thresholds() -> (Option<u8>, Option<u8>) {
+    match existing_audit_log() {
+        Some(audit_log) => (
+            read(audit_log, AUDIT_LOG_DEPLOYMENT_THRESHOLD),
+            read(audit_log, AUDIT_LOG_KEY_MANAGEMENT_THRESHOLD),
+        ),
+        None => (None, None),
+    }
+}
+
+/// Returns the recorded associated keys with their weights, and the deployment and key management
+/// thresholds, `None` unless the audit log holds a complete configuration of the account.
+pub fn recorded_configuration() -> Option<(BTreeMap<AccountHash, u8>, u8, u8)> {
+    let audit_log = existing_audit_log()?;
+    if !read::<bool>(audit_log, AUDIT_LOG_COMPLETE).unwrap_or_default() {
+        return None;
+    }
+    Some((
+        read(audit_log, AUDIT_LOG_KEYS).unwrap_or_default(),
+        read(audit_log, AUDIT_LOG_DEPLOYMENT_THRESHOLD)?,
+        read(audit_log, AUDIT_LOG_KEY_MANAGEMENT_THRESHOLD)?,
+    ))
+}
+
+fn append(audit_log: URef, record: AuditRecord) {
+    let length: u64 = read(audit_log, AUDIT_LOG_LENGTH).unwrap_or_default();
+    storage::dictionary_put(audit_log, &length.to_string(), record);
+    storage::dictionary_put(audit_log, AUDIT_LOG_LENGTH, length + 1);
+}
+
+/// Records a change of the associated key `account_hash`, `new_weight` being `None` once removed.
+pub fn record_key_change(operation: Operation, account_hash: AccountHash, new_weight: Option<u8>) {
+    let audit_log = audit_log();
+    let weight_key = account_hash.to_formatted_string();
+    // A key that could be added was absent, whatever was recorded for it before.
+    let old_weight = match operation {
+        Operation::AddKey => None,
+        _ => read::<Option<u8>>(audit_log, &weight_key).flatten(),
+    };
+    storage::dictionary_put(audit_log, &weight_key, new_weight);
+
+    let mut keys: BTreeMap<AccountHash, u8> = read(audit_log, AUDIT_LOG_KEYS).unwrap_or_default();
+    match new_weight {
+        Some(weight) => keys.insert(account_hash, weight),
+        None => keys.remove(&account_hash),
+    };
+    storage::dictionary_put(audit_log, AUDIT_LOG_KEYS, keys);
+
+    append(
+        audit_log,
+        AuditRecord {
+            operation,
+            account_hash: Some(account_hash),
+            weight: ValueChange {
+                old: old_weight,
+                new: new_weight,
+            },
+            deployment_threshold: ValueChange::default(),
+            key_management_threshold: ValueChange::default(),
+            block_time: runtime::get_blocktime().into(),
+        },
+    );
+}
+
+/// Records `keys` as every associated key of the account, once their changes have been recorded.
+///
+/// Keys recorded earlier but missing from `keys` are no longer associated. The change itself is
+/// not appended as a record.
+pub fn record_key_set(keys: BTreeMap<AccountHash, u8>) {
+    let audit_log = audit_log();
+    let recorded_keys: BTreeMap<AccountHash, u8> =
+        read(audit_log, AUDIT_LOG_KEYS).unwrap_or_default();
+    for account_hash in recorded_keys.keys() {
+        if !keys.contains_key(account_hash) {
+            storage::dictionary_put(audit_log, &account_hash.to_formatted_string(), None::<u8>);
+        }
+    }
+    storage::dictionary_put(audit_log, AUDIT_LOG_KEYS, keys);
+    storage::dictionary_put(audit_log, AUDIT_LOG_COMPLETE, true);
+}
+
+fn threshold_change(audit_log: URef, item_key: &str, new: Option<u8>) -> ValueChange {
+    match new {
+        Some(threshold) => {
+            let old = read(audit_log, item_key);
+            storage::dictionary_put(audit_log, item_key, threshold);
+            ValueChange {
+                old,
+                new: Some(threshold),
+            }
+        }
+        None => ValueChange::default(),
+    }
+}
+
+/// Records a change of the action thresholds, `None` for a threshold left untouched.
+pub fn record_threshold_change(
+    deployment_threshold: Option<u8>,
+    key_management_threshold: Option<u8>,
+) {
+    let audit_log = audit_log();
+    let deployment_threshold = threshold_change(
+        audit_log,
+        AUDIT_LOG_DEPLOYMENT_THRESHOLD,
+        deployment_threshold,
+    );
+    let key_management_threshold = threshold_change(
+        audit_log,
+        AUDIT_LOG_KEY_MANAGEMENT_THRESHOLD,
+        key_management_threshold,
+    );
+
+    append(
+        audit_log,
+        AuditRecord {
+            operation: Operation::UpdateThresholds,
+            account_hash: None,
+            weight: ValueChange::default(),
+            deployment_threshold,
+            key_management_threshold,
+            block_time: runtime::get_blocktime().into(),
+        },
+    );
+}
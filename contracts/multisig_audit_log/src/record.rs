@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
+use casper_types::{CLType, CLTyped};
+
+/// The key-management operation recorded in the audit log.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// An associated key was added.
+    AddKey = 0,
+    /// An associated key was removed.
+    RemoveKey = 1,
+    /// The weight of an associated key was changed.
+    UpdateKeyWeight = 2,
+    /// One or both action thresholds were changed.
+    UpdateThresholds = 3,
+}
+
+impl CLTyped for Operation {
+    fn cl_type() -> CLType {
+        u8::cl_type()
+    }
+}
+
+impl ToBytes for Operation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for Operation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let operation = match tag {
+            0 => Operation::AddKey,
+            1 => Operation::RemoveKey,
+            2 => Operation::UpdateKeyWeight,
+            3 => Operation::UpdateThresholds,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((operation, remainder))
+    }
+}
+
+/// The old and new value of a weight or threshold.
+///
+/// `None` means the key was absent, the value was left untouched, or it was never recorded before.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValueChange {
+    pub old: Option<u8>,
+    pub new: Option<u8>,
+}
+
+impl CLTyped for ValueChange {
+    fn cl_type() -> CLType {
+        <(Option<u8>, Option<u8>)>::cl_type()
+    }
+}
+
+impl ToBytes for ValueChange {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.old.to_bytes()?);
+        result.append(&mut self.new.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.old.serialized_length() + self.new.serialized_length()
+    }
+}
+
+impl FromBytes for ValueChange {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (old, remainder) = Option::<u8>::from_bytes(bytes)?;
+        let (new, remainder) = Option::<u8>::from_bytes(remainder)?;
+        Ok((ValueChange { old, new }, remainder))
+    }
+}
+
+/// A single entry of the audit log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditRecord {
+    pub operation: Operation,
+    /// The associated key that was changed, `None` for threshold updates.
+    pub account_hash: Option<AccountHash>,
+    pub weight: ValueChange,
+    pub deployment_threshold: ValueChange,
+    pub key_management_threshold: ValueChange,
+    /// The block time of the deploy, in milliseconds since the Unix epoch.
+    pub block_time: u64,
+}
+
+/// Typed as nested tuples of the fields in order, which serialize to the same bytes, as a tuple
+/// holds at most three values.
+impl CLTyped for AuditRecord {
+    fn cl_type() -> CLType {
+        <(
+            (Operation, Option<AccountHash>, ValueChange),
+            (ValueChange, ValueChange),
+            u64,
+        )>::cl_type()
+    }
+}
+
+impl ToBytes for AuditRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.operation.to_bytes()?);
+        result.append(&mut self.account_hash.to_bytes()?);
+        result.append(&mut self.weight.to_bytes()?);
+        result.append(&mut self.deployment_threshold.to_bytes()?);
+        result.append(&mut self.key_management_threshold.to_bytes()?);
+        result.append(&mut self.block_time.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.operation.serialized_length()
+            + self.account_hash.serialized_length()
+            + self.weight.serialized_length()
+            + self.deployment_threshold.serialized_length()
+            + self.key_management_threshold.serialized_length()
+            + self.block_time.serialized_length()
+    }
+}
+
+impl FromBytes for AuditRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (operation, remainder) = Operation::from_bytes(bytes)?;
+        let (account_hash, remainder) = Option::<AccountHash>::from_bytes(remainder)?;
+        let (weight, remainder) = ValueChange::from_bytes(remainder)?;
+        let (deployment_threshold, remainder) = ValueChange::from_bytes(remainder)?;
+        let (key_management_threshold, remainder) = ValueChange::from_bytes(remainder)?;
+        let (block_time, remainder) = u64::from_bytes(remainder)?;
+        let record = AuditRecord {
+            operation,
+            account_hash,
+            weight,
+            deployment_threshold,
+            key_management_threshold,
+            block_time,
+        };
+        Ok((record, remainder))
+    }
+}
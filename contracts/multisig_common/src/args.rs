@@ -0,0 +1,75 @@
+//! Argument parsing shared by every key management operation.
+
+use casper_contract::contract_api::runtime;
+use casper_contract::ext_ffi;
+use casper_types::account::AccountHash;
+use casper_types::{api_error, ApiError, Key, PublicKey};
+
+/// Returns whether the deploy supplied the named argument.
+pub fn has_named_arg(name: &str) -> bool {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => true,
+        Err(ApiError::MissingArgument) => false,
+        Err(error) => runtime::revert(error),
+    }
+}
+
+/// Returns the account behind `key`, reverting with `error` if it is not an account.
+pub fn key_to_account_hash<E: Into<ApiError>>(key: Key, error: E) -> AccountHash {
+    if let Key::Account(account) = key {
+        account
+    } else {
+        runtime::revert(error);
+    }
+}
+
+/// Reads an account from whichever of the `PublicKey`, `AccountHash` or `Key` arguments was
+/// supplied, reverting with `error` if more than one was, or if the key is not an account.
+pub fn get_account_arg<E: Into<ApiError> + Copy>(
+    public_key_arg: &str,
+    account_hash_arg: &str,
+    key_arg: &str,
+    error: E,
+) -> AccountHash {
+    let has_public_key = has_named_arg(public_key_arg);
+    let has_account_hash = has_named_arg(account_hash_arg);
+    let has_key = has_named_arg(key_arg);
+
+    if [has_public_key, has_account_hash, has_key]
+        .iter()
+        .filter(|&&supplied| supplied)
+        .count()
+        > 1
+    {
+        runtime::revert(error);
+    }
+
+    if has_public_key {
+        let public_key: PublicKey = runtime::get_named_arg(public_key_arg);
+        if let PublicKey::System = public_key {
+            runtime::revert(error);
+        }
+        AccountHash::from_public_key(&public_key, runtime::blake2b)
+    } else if has_account_hash {
+        runtime::get_named_arg(account_hash_arg)
+    } else {
+        key_to_account_hash(runtime::get_named_arg(key_arg), error)
+    }
+}
+
+/// Reads an optional `u8` argument, `None` when it was not supplied.
+pub fn get_optional_u8_arg(name: &str) -> Option<u8> {
+    if has_named_arg(name) {
+        Some(runtime::get_named_arg(name))
+    } else {
+        None
+    }
+}
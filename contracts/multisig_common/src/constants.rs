@@ -0,0 +1,37 @@
+pub const RUNTIME_ARG_OPERATION: &str = "operation";
+pub const RUNTIME_ARG_OPERATIONS: &str = "operations";
+
+pub const OPERATION_ADD: &str = "add";
+pub const OPERATION_REMOVE: &str = "remove";
+pub const OPERATION_UPDATE_WEIGHT: &str = "update_weight";
+pub const OPERATION_SET_THRESHOLDS: &str = "set_thresholds";
+pub const OPERATION_ROTATE: &str = "rotate";
+
+// Arguments of the `add` operation and the `add_account` session code.
+pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY: &str = "new_key";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_PUBLIC_KEY: &str = "new_public_key";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH: &str = "new_account_hash";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT: &str = "weight";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY: &str = "expires_at";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL: &str = "label";
+pub const RUNTIME_ARG_NEW_ASSOCIATED_KEYS: &str = "new_keys";
+
+// Arguments of the `remove` operation and the `remove_account` session code.
+pub const RUNTIME_ARG_REMOVE_ASSOCIATED_KEY: &str = "remove_key";
+pub const RUNTIME_ARG_REMOVE_ASSOCIATED_PUBLIC_KEY: &str = "remove_public_key";
+pub const RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH: &str = "remove_account_hash";
+
+// Arguments of the `update_weight` operation and the `update_associated_keys` session code.
+pub const RUNTIME_ARG_ASSOCIATED_KEY: &str = "associated_key";
+pub const RUNTIME_ARG_ASSOCIATED_PUBLIC_KEY: &str = "associated_public_key";
+pub const RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH: &str = "associated_account_hash";
+pub const RUNTIME_ARG_NEW_KEY_WEIGHT: &str = "new_weight";
+
+// Thresholds taken by the `set_thresholds` operation and every session code working on thresholds.
+pub const RUNTIME_ARG_DEPLOYMENT_THRESHOLD: &str = "deployment_threshold";
+pub const RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD: &str = "key_management_threshold";
+
+// Arguments of the `rotate` operation and the `rotate_key` session code.
+pub const RUNTIME_ARG_OLD_ASSOCIATED_KEY: &str = "old_key";
+pub const RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY: &str = "new_key";
+pub const RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT: &str = "weight";
@@ -0,0 +1,335 @@
+//! User errors raised by the multi-signature session contracts.
+//!
+//! Every contract owns a range of 100 codes, so an `ApiError::User` code reported by a failed
+//! deploy identifies both the contract and the reason. Codes are stable: new variants take the
+//! next free code in their contract's range and existing codes are never reused.
+//!
+//! | Contract                 | Codes       |
+//! |--------------------------|-------------|
+//! | `add_account`            | 100 - 199   |
+//! | `configure_multisig`     | 200 - 299   |
+//! | `remove_account`         | 300 - 399   |
+//! | `rotate_key`             | 400 - 499   |
+//! | `update_associated_keys` | 500 - 599   |
+//! | `update_thresholds`      | 600 - 699   |
+//! | `freeze_account`         | 700 - 799   |
+//! | `unfreeze_account`       | 800 - 899   |
+//! | `sweep_expired_keys`     | 900 - 999   |
+//! | `multisig_wallet`        | 1000 - 1099 |
+//! | `key_change_proposals`   | 1100 - 1199 |
+//! | `apply_key_change`       | 1200 - 1299 |
+//! | `queue_key_change`       | 1300 - 1399 |
+//! | `execute_key_change`     | 1400 - 1499 |
+//! | `cancel_key_change`      | 1500 - 1599 |
+//! | `transfer`               | 1600 - 1699 |
+//! | `set_spending_limit`     | 1700 - 1799 |
+//! | `delegate`               | 1800 - 1899 |
+//! | `undelegate`             | 1900 - 1999 |
+//! | `redelegate`             | 2000 - 2099 |
+//! | `key_manager`            | 2100 - 2199 |
+//! | `snapshot_account`       | 2200 - 2299 |
+
+use casper_types::ApiError;
+
+macro_rules! user_errors {
+    (
+        $(#[$enum_meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident = $code:literal => $message:literal,)+
+        }
+    ) => {
+        $(#[$enum_meta])*
+        #[repr(u16)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $(#[doc = $message] $variant = $code,)+
+        }
+
+        impl $name {
+            /// Every variant, in code order.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)+];
+
+            /// Returns the variant reported as `ApiError::User(code)`, if `code` belongs to it.
+            pub fn from_code(code: u16) -> Option<Self> {
+                match code {
+                    $($code => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Returns a human-readable description of the error.
+            pub fn message(self) -> &'static str {
+                match self {
+                    $($name::$variant => $message,)+
+                }
+            }
+        }
+
+        impl From<$name> for ApiError {
+            fn from(error: $name) -> Self {
+                ApiError::User(error as u16)
+            }
+        }
+    };
+}
+
+user_errors! {
+    /// Errors raised by the `add_account` session code.
+    pub enum AddAccountError {
+        InvalidAccount = 100 => "the key to add is not an account",
+        EmptyBatch = 101 => "the batch of keys to add is empty",
+        DuplicateKey = 102 => "the key is already associated with the account",
+        MaxKeysLimit = 103 => "the account already has the maximum number of associated keys",
+        PermissionDenied = 104 => "the deploy does not meet the key management threshold",
+        InvalidLabel = 105 => "the label is empty or longer than 64 bytes",
+        InvalidExpiry = 106 => "the expiry is not after the block time of the deploy",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `configure_multisig` session code.
+    pub enum ConfigureMultisigError {
+        InvalidAccount = 200 => "an associated key is not an account or is the primary key",
+        InvalidThresholds = 201 => "the deployment threshold is above the key management threshold",
+        UnreachableThresholds = 202 => "the configured keys cannot reach the configured thresholds",
+        MaxKeysLimit = 203 => "the account cannot hold any more associated keys",
+        PermissionDenied = 204 => "the deploy does not meet the key management threshold",
+        MissingPrimaryKey = 205 => "the primary key is not associated with the account",
+        UnlistedKeys = 206 => "keys that are not listed still carry weight on the account",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `remove_account` session code.
+    pub enum RemoveAccountError {
+        InvalidAccount = 300 => "the key to remove is not an account",
+        LockoutRisk = 301 => "removing the key would leave the thresholds unreachable",
+        MissingKey = 302 => "the key is not associated with the account",
+        PermissionDenied = 303 => "the deploy does not meet the key management threshold",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `rotate_key` session code.
+    pub enum RotateKeyError {
+        InvalidAccount = 400 => "the old or the new key is not an account",
+        MissingKey = 401 => "the old key is not associated with the account",
+        DuplicateKey = 402 => "the new key is already associated with the account",
+        LockoutRisk = 403 => "rotating the key would leave the thresholds unreachable",
+        ZeroWeight = 404 => "the new key's weight is zero",
+        WeightMismatch = 405 => "the weight differs from the old key's weight recorded by the audit log",
+        UnknownWeight = 406 => "the weight is missing and the audit log does not record the old key's weight",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `update_associated_keys` session code.
+    pub enum UpdateAssociatedKeysError {
+        InvalidAccount = 500 => "the key to update is not an account",
+        MissingKey = 501 => "the key is not associated with the account",
+        PermissionDenied = 502 => "the deploy does not meet the key management threshold",
+        LockoutRisk = 503 => "the new weight would leave the thresholds unreachable",
+        ZeroWeight = 504 => "the new weight is zero, remove the key instead",
+        NoChange = 505 => "the new weight is the weight last recorded for the key",
+        UnrecordedThresholds = 506 => "a threshold was changed without being recorded, and checking it would lose it",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `update_thresholds` session code.
+    pub enum UpdateThresholdsError {
+        InvalidThresholds = 600 => "the deployment threshold would be above the key management threshold",
+        MissingThresholds = 601 => "neither threshold was supplied",
+        UnreachableThreshold = 602 => "the threshold is above the total weight of the associated keys",
+        PermissionDenied = 603 => "the deploy does not meet the key management threshold",
+        NoChange = 604 => "the new thresholds are the thresholds last recorded",
+        UnrecordedThresholds = 605 => "a threshold was changed without being recorded, and checking it would lose it",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `freeze_account` session code.
+    pub enum FreezeAccountError {
+        AlreadyFrozen = 700 => "the account is already frozen",
+        InvalidThreshold = 701 => "the supplied deployment threshold is above the key management threshold",
+        ThresholdMismatch = 702 => "the supplied thresholds are not the account's current thresholds",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `unfreeze_account` session code.
+    pub enum UnfreezeAccountError {
+        NotFrozen = 800 => "the account is not frozen",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `sweep_expired_keys` session code.
+    pub enum SweepExpiredKeysError {
+        LockoutRisk = 900 => "removing the expired keys would leave the thresholds unreachable",
+        PermissionDenied = 901 => "the deploy does not meet the key management threshold",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `multisig_wallet` contract and its installer.
+    pub enum MultisigWalletError {
+        AlreadyInstalled = 1000 => "the account already installed a multisig wallet",
+        InvalidSigners = 1001 => "the signers are empty or contain duplicates",
+        InvalidThreshold = 1002 => "the threshold is zero or above the number of signers",
+        NotASigner = 1003 => "the caller is not a signer of the wallet",
+        InvalidAmount = 1004 => "the amount to transfer is zero",
+        MissingProposal = 1005 => "the proposal does not exist",
+        AlreadyExecuted = 1006 => "the proposal was already executed",
+        AlreadyApproved = 1007 => "the caller already approved the proposal",
+        NotApproved = 1008 => "the caller has not approved the proposal",
+        InsufficientApprovals = 1009 => "the proposal does not have enough approvals",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `key_change_proposals` contract and its installer.
+    pub enum KeyChangeProposalsError {
+        AlreadyInstalled = 1100 => "the account already installed a key change proposals contract",
+        InvalidSigners = 1101 => "the signers are empty or one has a zero weight",
+        InvalidThreshold = 1102 => "the approval threshold is zero or above the total signer weight",
+        NotASigner = 1103 => "the caller is not a signer of the contract",
+        NotOwner = 1104 => "the caller is not the account owning the contract",
+        ZeroWeight = 1105 => "the proposed weight is zero, propose a removal instead",
+        InvalidThresholds = 1106 => "the proposed deployment threshold is above the key management threshold",
+        MissingProposal = 1107 => "the proposal does not exist",
+        AlreadyApplied = 1108 => "the proposal was already applied",
+        AlreadyApproved = 1109 => "the caller already approved the proposal",
+        NotApproved = 1110 => "the caller has not approved the proposal",
+        InsufficientApprovals = 1111 => "the proposal does not have enough approval weight",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `apply_key_change` session code, and by `execute_key_change` when
+    /// applying a change.
+    pub enum ApplyKeyChangeError {
+        NotInstalled = 1200 => "the account has not installed a key change proposals contract",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `queue_key_change` session code.
+    pub enum QueueKeyChangeError {
+        InvalidChange = 1300 => "exactly one of a key to add, a key to remove or thresholds must be supplied",
+        ZeroWeight = 1301 => "the weight of the key to add is zero",
+        InvalidThresholds = 1302 => "the deployment threshold is above the key management threshold",
+        DelayTooShort = 1303 => "the delay is shorter than the minimum delay",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `execute_key_change` session code.
+    pub enum ExecuteKeyChangeError {
+        MissingChange = 1400 => "the change is not queued, or was executed or cancelled",
+        TooEarly = 1401 => "the delay of the queued change has not passed yet",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `cancel_key_change` session code.
+    pub enum CancelKeyChangeError {
+        MissingChange = 1500 => "the change is not queued, or was executed or cancelled",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `transfer` session code.
+    pub enum TransferError {
+        InvalidAmount = 1600 => "the amount to transfer is zero",
+        LimitExceeded = 1601 => "the transfer exceeds the spending limit and the deploy does not meet the key management threshold",
+        MaxKeysLimit = 1602 => "the account has the maximum number of associated keys, leaving no room to check the key management weight",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `set_spending_limit` session code.
+    pub enum SetSpendingLimitError {
+        InvalidPeriod = 1700 => "the spending period is zero",
+        PermissionDenied = 1701 => "the deploy does not meet the key management threshold",
+        MaxKeysLimit = 1702 => "the account has the maximum number of associated keys, leaving no room to check the key management weight",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `delegate` session code.
+    pub enum DelegateError {
+        InvalidDelegator = 1800 => "the delegator public key is not the key of the account",
+        InvalidAmount = 1801 => "the amount to delegate is zero",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `undelegate` session code.
+    pub enum UndelegateError {
+        InvalidDelegator = 1900 => "the delegator public key is not the key of the account",
+        InvalidAmount = 1901 => "the amount to undelegate is zero",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `redelegate` session code.
+    pub enum RedelegateError {
+        InvalidDelegator = 2000 => "the delegator public key is not the key of the account",
+        InvalidAmount = 2001 => "the amount to redelegate is zero",
+        SameValidator = 2002 => "the new validator is the current validator",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `key_manager` session code itself. A failed operation reports the
+    /// error of the single-purpose session code performing the same update.
+    pub enum KeyManagerError {
+        MissingOperation = 2100 => "neither an operation nor a list of operations was supplied",
+        ConflictingOperations = 2101 => "both an operation and a list of operations were supplied",
+        UnknownOperation = 2102 => "the operation is not one of add, remove, update_weight, set_thresholds or rotate",
+        EmptyOperations = 2103 => "the list of operations is empty",
+    }
+}
+
+user_errors! {
+    /// Errors raised by the `snapshot_account` session code.
+    pub enum SnapshotAccountError {
+        Unconfigured = 2200 => "the audit log holds no complete configuration of the account",
+        Mismatch = 2201 => "the recorded configuration differs from the account",
+    }
+}
+
+/// Returns the description of the user error reported as `ApiError::User(code)` by any of the
+/// multi-signature session contracts.
+pub fn user_error_message(code: u16) -> Option<&'static str> {
+    match code {
+        100..=199 => AddAccountError::from_code(code).map(AddAccountError::message),
+        200..=299 => ConfigureMultisigError::from_code(code).map(ConfigureMultisigError::message),
+        300..=399 => RemoveAccountError::from_code(code).map(RemoveAccountError::message),
+        400..=499 => RotateKeyError::from_code(code).map(RotateKeyError::message),
+        500..=599 => {
+            UpdateAssociatedKeysError::from_code(code).map(UpdateAssociatedKeysError::message)
+        }
+        600..=699 => UpdateThresholdsError::from_code(code).map(UpdateThresholdsError::message),
+        700..=799 => FreezeAccountError::from_code(code).map(FreezeAccountError::message),
+        800..=899 => UnfreezeAccountError::from_code(code).map(UnfreezeAccountError::message),
+        900..=999 => SweepExpiredKeysError::from_code(code).map(SweepExpiredKeysError::message),
+        1000..=1099 => MultisigWalletError::from_code(code).map(MultisigWalletError::message),
+        1100..=1199 => {
+            KeyChangeProposalsError::from_code(code).map(KeyChangeProposalsError::message)
+        }
+        1200..=1299 => ApplyKeyChangeError::from_code(code).map(ApplyKeyChangeError::message),
+        1300..=1399 => QueueKeyChangeError::from_code(code).map(QueueKeyChangeError::message),
+        1400..=1499 => ExecuteKeyChangeError::from_code(code).map(ExecuteKeyChangeError::message),
+        1500..=1599 => CancelKeyChangeError::from_code(code).map(CancelKeyChangeError::message),
+        1600..=1699 => TransferError::from_code(code).map(TransferError::message),
+        1700..=1799 => SetSpendingLimitError::from_code(code).map(SetSpendingLimitError::message),
+        1800..=1899 => DelegateError::from_code(code).map(DelegateError::message),
+        1900..=1999 => UndelegateError::from_code(code).map(UndelegateError::message),
+        2000..=2099 => RedelegateError::from_code(code).map(RedelegateError::message),
+        2100..=2199 => KeyManagerError::from_code(code).map(KeyManagerError::message),
+        2200..=2299 => SnapshotAccountError::from_code(code).map(SnapshotAccountError::message),
+        _ => None,
+    }
+}
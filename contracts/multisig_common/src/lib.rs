@@ -0,0 +1,12 @@
+//! Argument names, user errors and helpers shared by the multi-signature session contracts and
+//! their clients.
+#![no_std]
+
+extern crate alloc;
+
+pub mod args;
+pub mod constants;
+pub mod errors;
+pub mod proposals;
+pub mod reachability;
+pub mod weight;
@@ -0,0 +1,123 @@
+//! Proposals approved by a set of signers, shared by the contracts that store them.
+//!
+//! Each contract keeps its proposals in a dictionary named by `Proposal::DICTIONARY`, keyed by
+//! the proposal number, and its signers under a named key of its own.
+
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{FromBytes, ToBytes};
+use casper_types::{ApiError, CLTyped, URef};
+
+/// A stored proposal, approved by signers until it is closed.
+pub trait Proposal: CLTyped + FromBytes + ToBytes + Sized {
+    /// The user errors reported by the contract storing the proposal.
+    type Error: Into<ApiError>;
+
+    /// The name of the dictionary holding the proposals.
+    const DICTIONARY: &'static str;
+    const MISSING_PROPOSAL: Self::Error;
+    const CLOSED: Self::Error;
+    const ALREADY_APPROVED: Self::Error;
+    const NOT_APPROVED: Self::Error;
+
+    /// The signers approving the proposal, the proposer first.
+    fn approvals(&mut self) -> &mut Vec<AccountHash>;
+
+    /// Returns whether the proposal was carried out, after which it takes no more approvals.
+    fn is_closed(&self) -> bool;
+}
+
+/// A set of signers stored by a contract.
+pub trait Signers: CLTyped + FromBytes {
+    fn contains_signer(&self, account: &AccountHash) -> bool;
+}
+
+impl Signers for Vec<AccountHash> {
+    fn contains_signer(&self, account: &AccountHash) -> bool {
+        self.contains(account)
+    }
+}
+
+impl Signers for BTreeMap<AccountHash, u8> {
+    fn contains_signer(&self, account: &AccountHash) -> bool {
+        self.contains_key(account)
+    }
+}
+
+pub fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert()
+}
+
+pub fn read_named_value<T: CLTyped + FromBytes>(name: &str) -> T {
+    storage::read(get_uref(name))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Returns the caller, reverting with `error` unless it is one of the signers stored under
+/// `signers_key`.
+pub fn get_signer<S: Signers, E: Into<ApiError>>(signers_key: &str, error: E) -> AccountHash {
+    let caller = runtime::get_caller();
+    let signers: S = read_named_value(signers_key);
+    if !signers.contains_signer(&caller) {
+        runtime::revert(error);
+    }
+    caller
+}
+
+/// Reads a proposal, reverting if it is missing or closed.
+pub fn read_proposal<P: Proposal>(proposal_id: u64) -> P {
+    let proposal: P = storage::dictionary_get(get_uref(P::DICTIONARY), &proposal_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(P::MISSING_PROPOSAL);
+    if proposal.is_closed() {
+        runtime::revert(P::CLOSED);
+    }
+    proposal
+}
+
+pub fn write_proposal<P: Proposal>(proposal_id: u64, proposal: P) {
+    storage::dictionary_put(get_uref(P::DICTIONARY), &proposal_id.to_string(), proposal);
+}
+
+/// Stores a new proposal under the next number counted under `count_key`, returning the number.
+pub fn add_proposal<P: Proposal>(count_key: &str, proposal: P) -> u64 {
+    let proposal_count = get_uref(count_key);
+    let proposal_id: u64 = storage::read(proposal_count)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    storage::write(proposal_count, proposal_id + 1);
+
+    write_proposal(proposal_id, proposal);
+    proposal_id
+}
+
+/// Adds the approval of `signer` to an open proposal.
+pub fn approve<P: Proposal>(signer: AccountHash, proposal_id: u64) {
+    let mut proposal: P = read_proposal(proposal_id);
+
+    if proposal.approvals().contains(&signer) {
+        runtime::revert(P::ALREADY_APPROVED);
+    }
+    proposal.approvals().push(signer);
+
+    write_proposal(proposal_id, proposal);
+}
+
+/// Takes back the approval of `signer` from an open proposal.
+pub fn revoke_approval<P: Proposal>(signer: AccountHash, proposal_id: u64) {
+    let mut proposal: P = read_proposal(proposal_id);
+
+    if !proposal.approvals().contains(&signer) {
+        runtime::revert(P::NOT_APPROVED);
+    }
+    proposal.approvals().retain(|approval| *approval != signer);
+
+    write_proposal(proposal_id, proposal);
+}
@@ -0,0 +1,229 @@
+//! Threshold reachability of a set of weighted keys.
+//!
+//! Session code cannot read the associated keys or action thresholds of an account, so these
+//! functions take them as input. Clients can check an account read from a node, and contracts can
+//! check the signer sets they store.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use casper_types::account::AccountHash;
+
+use crate::weight::reachable_weight;
+
+/// Whether a single threshold can be met by the keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThresholdReachability {
+    pub reachable: bool,
+    /// The fewest keys whose weights together meet the threshold, `None` if none do.
+    pub min_signers: Option<usize>,
+}
+
+/// Reachability of both action thresholds of an account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reachability {
+    pub deployment: ThresholdReachability,
+    pub key_management: ThresholdReachability,
+    /// The keys whose loss alone would leave either threshold unreachable. Every key is listed
+    /// when a threshold is already unreachable.
+    pub critical_keys: BTreeSet<AccountHash>,
+}
+
+impl Reachability {
+    /// Returns whether both thresholds are reachable.
+    pub fn is_reachable(&self) -> bool {
+        self.deployment.reachable && self.key_management.reachable
+    }
+}
+
+/// Returns the fewest keys of `weights` that meet `threshold`, `None` if all of them together
+/// fall short.
+pub fn min_signers<I: IntoIterator<Item = u8>>(weights: I, threshold: u8) -> Option<usize> {
+    let mut weights: Vec<u8> = weights.into_iter().collect();
+    // The heaviest keys first, so no smaller set of keys reaches more weight.
+    weights.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut total = 0;
+    let mut signers = 0;
+    for weight in weights {
+        if total >= u32::from(threshold) {
+            break;
+        }
+        total += u32::from(weight);
+        signers += 1;
+    }
+
+    if total >= u32::from(threshold) {
+        Some(signers)
+    } else {
+        None
+    }
+}
+
+/// Returns whether a single threshold can be met by the keys of `weights`.
+pub fn check_threshold<I: IntoIterator<Item = u8>>(
+    weights: I,
+    threshold: u8,
+) -> ThresholdReachability {
+    let min_signers = min_signers(weights, threshold);
+    ThresholdReachability {
+        reachable: min_signers.is_some(),
+        min_signers,
+    }
+}
+
+/// Returns the reachability of the deployment and key management thresholds by `keys`.
+pub fn check_reachability(
+    keys: &BTreeMap<AccountHash, u8>,
+    deployment_threshold: u8,
+    key_management_threshold: u8,
+) -> Reachability {
+    let total_weight = reachable_weight(keys.values().copied());
+    let highest_threshold = u32::from(deployment_threshold.max(key_management_threshold));
+    let critical_keys = keys
+        .iter()
+        .filter(|(_, weight)| total_weight - u32::from(**weight) < highest_threshold)
+        .map(|(account, _)| *account)
+        .collect();
+
+    Reachability {
+        deployment: check_threshold(keys.values().copied(), deployment_threshold),
+        key_management: check_threshold(keys.values().copied(), key_management_threshold),
+        critical_keys,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::vec::Vec;
+    use casper_types::account::AccountHash;
+
+    use super::{check_reachability, check_threshold, ThresholdReachability};
+
+    /// Returns the fewest keys of `weights` that meet `threshold` by trying every subset of them.
+    fn brute_force_min_signers(weights: &[u8], threshold: u8) -> Option<usize> {
+        (0u32..1 << weights.len())
+            .filter(|subset| {
+                let subset_weight: u32 = weights
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| subset & (1 << index) != 0)
+                    .map(|(_, weight)| u32::from(*weight))
+                    .sum();
+                subset_weight >= u32::from(threshold)
+            })
+            .map(|subset| subset.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn should_report_threshold_reachability_of_every_small_account() {
+        const MAX_WEIGHT: u8 = 3;
+        let accounts = [
+            AccountHash::new([0u8; 32]),
+            AccountHash::new([1u8; 32]),
+            AccountHash::new([2u8; 32]),
+            AccountHash::new([3u8; 32]),
+        ];
+
+        for key_count in 0..=accounts.len() {
+            let weight_choices = u32::from(MAX_WEIGHT) + 1;
+            for assignment in 0..weight_choices.pow(key_count as u32) {
+                // Decodes `assignment` as one base `weight_choices` digit per key.
+                let keys: BTreeMap<AccountHash, u8> = accounts[..key_count]
+                    .iter()
+                    .enumerate()
+                    .map(|(index, account)| {
+                        let weight = assignment / weight_choices.pow(index as u32) % weight_choices;
+                        (*account, weight as u8)
+                    })
+                    .collect();
+                let weights: Vec<u8> = keys.values().copied().collect();
+                let max_threshold = MAX_WEIGHT * key_count as u8 + 1;
+
+                for deployment in 0..=max_threshold {
+                    for key_management in 0..=max_threshold {
+                        let reachability = check_reachability(&keys, deployment, key_management);
+
+                        let expected_deployment = brute_force_min_signers(&weights, deployment);
+                        let expected_key_management =
+                            brute_force_min_signers(&weights, key_management);
+                        assert_eq!(reachability.deployment.min_signers, expected_deployment);
+                        assert_eq!(
+                            reachability.deployment.reachable,
+                            expected_deployment.is_some()
+                        );
+                        assert_eq!(
+                            reachability.key_management.min_signers,
+                            expected_key_management
+                        );
+                        assert_eq!(
+                            reachability.key_management.reachable,
+                            expected_key_management.is_some()
+                        );
+                        assert_eq!(
+                            reachability.is_reachable(),
+                            expected_deployment.is_some() && expected_key_management.is_some()
+                        );
+
+                        let expected_critical_keys: BTreeSet<AccountHash> = keys
+                            .keys()
+                            .filter(|account| {
+                                let remaining: Vec<u8> = keys
+                                    .iter()
+                                    .filter(|(other, _)| other != account)
+                                    .map(|(_, weight)| *weight)
+                                    .collect();
+                                brute_force_min_signers(&remaining, deployment).is_none()
+                                    || brute_force_min_signers(&remaining, key_management).is_none()
+                            })
+                            .copied()
+                            .collect();
+                        assert_eq!(reachability.critical_keys, expected_critical_keys);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_report_threshold_reachability_of_maximum_weights() {
+        const PRIMARY_ACCOUNT: AccountHash = AccountHash::new([0u8; 32]);
+        const ACCOUNT_1: AccountHash = AccountHash::new([1u8; 32]);
+        const ACCOUNT_2: AccountHash = AccountHash::new([2u8; 32]);
+
+        let keys: BTreeMap<AccountHash, u8> = [
+            (PRIMARY_ACCOUNT, u8::MAX),
+            (ACCOUNT_1, u8::MAX),
+            (ACCOUNT_2, 1),
+        ]
+        .iter()
+        .copied()
+        .collect();
+
+        let reachability = check_reachability(&keys, u8::MAX, u8::MAX);
+        assert!(reachability.is_reachable());
+        assert_eq!(reachability.key_management.min_signers, Some(1));
+        assert!(reachability.critical_keys.is_empty());
+
+        // Without the second heavy key, the primary key is the only one meeting the threshold.
+        let keys: BTreeMap<AccountHash, u8> = [(PRIMARY_ACCOUNT, u8::MAX), (ACCOUNT_2, 1)]
+            .iter()
+            .copied()
+            .collect();
+
+        let reachability = check_reachability(&keys, 1, u8::MAX);
+        assert_eq!(reachability.deployment.min_signers, Some(1));
+        assert_eq!(
+            reachability.critical_keys,
+            [PRIMARY_ACCOUNT].iter().copied().collect()
+        );
+        assert_eq!(
+            check_threshold(keys.values().copied(), u8::MAX),
+            ThresholdReachability {
+                reachable: true,
+                min_signers: Some(1),
+            }
+        );
+    }
+}
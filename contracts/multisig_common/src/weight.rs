@@ -0,0 +1,24 @@
+//! Weight arithmetic that can run both in contracts and in clients.
+
+/// Returns the weight reached when every key of `weights` signs.
+///
+/// The sum is widened to `u32`, so it never overflows, unlike the `u8` a single weight or
+/// threshold fits in.
+pub fn reachable_weight<I: IntoIterator<Item = u8>>(weights: I) -> u32 {
+    weights.into_iter().map(u32::from).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::reachable_weight;
+
+    #[test]
+    fn should_compute_reachable_weight_without_overflow() {
+        assert_eq!(reachable_weight(Vec::new()), 0);
+        assert_eq!(reachable_weight(vec![1, 2, 3]), 6);
+        assert_eq!(reachable_weight(vec![u8::MAX, u8::MAX]), 510);
+    }
+}
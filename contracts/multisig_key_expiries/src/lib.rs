@@ -0,0 +1,92 @@
+//! Expiry timestamps of the temporary associated keys of a multi-signature account.
+//!
+//! Expiries are stored in the `multisig_key_expiries` dictionary, owned by the account under the
+//! named key of the same name. Items are keyed by the formatted account hash of the associated
+//! key (`account-hash-...`) and hold an `Option<u64>`, the block time in milliseconds from which
+//! the key is expired, or `None` once the key no longer expires. The `expiring_keys` item lists the
+//! account hashes of all keys with an expiry, as session code cannot iterate a dictionary.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::URef;
+
+pub const DICTIONARY_KEY_EXPIRIES: &str = "multisig_key_expiries";
+pub const KEY_EXPIRIES_EXPIRING_KEYS: &str = "expiring_keys";
+
+fn key_expiries() -> Option<URef> {
+    runtime::get_key(DICTIONARY_KEY_EXPIRIES).map(|key| key.into_uref().unwrap_or_revert())
+}
+
+fn read_expiry(key_expiries: URef, account_hash: AccountHash) -> Option<u64> {
+    storage::dictionary_get::<Option<u64>>(key_expiries, &account_hash.to_formatted_string())
+        .unwrap_or_revert()
+        .flatten()
+}
+
+fn read_expiring_keys(key_expiries: URef) -> Vec<AccountHash> {
+    storage::dictionary_get(key_expiries, KEY_EXPIRIES_EXPIRING_KEYS)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_expiry(key_expiries: URef, account_hash: AccountHash, expires_at: Option<u64>) {
+    storage::dictionary_put(
+        key_expiries,
+        &account_hash.to_formatted_string(),
+        expires_at,
+    );
+
+    let mut expiring_keys = read_expiring_keys(key_expiries);
+    expiring_keys.retain(|expiring_key| *expiring_key != account_hash);
+    if expires_at.is_some() {
+        expiring_keys.push(account_hash);
+    }
+    storage::dictionary_put(key_expiries, KEY_EXPIRIES_EXPIRING_KEYS, expiring_keys);
+}
+
+/// Makes the associated key `account_hash` expire at the block time `expires_at`.
+pub fn set_expiry(account_hash: AccountHash, expires_at: u64) {
+    let key_expiries = match key_expiries() {
+        Some(key_expiries) => key_expiries,
+        None => storage::new_dictionary(DICTIONARY_KEY_EXPIRIES).unwrap_or_revert(),
+    };
+    write_expiry(key_expiries, account_hash, Some(expires_at));
+}
+
+/// Deletes the expiry of the associated key `account_hash`, if any.
+pub fn clear_expiry(account_hash: AccountHash) {
+    if let Some(key_expiries) = key_expiries() {
+        if read_expiry(key_expiries, account_hash).is_some() {
+            write_expiry(key_expiries, account_hash, None);
+        }
+    }
+}
+
+/// Moves the expiry of the associated key `old_account_hash` to `new_account_hash`, if any.
+pub fn move_expiry(old_account_hash: AccountHash, new_account_hash: AccountHash) {
+    if let Some(key_expiries) = key_expiries() {
+        if let Some(expires_at) = read_expiry(key_expiries, old_account_hash) {
+            write_expiry(key_expiries, old_account_hash, None);
+            write_expiry(key_expiries, new_account_hash, Some(expires_at));
+        }
+    }
+}
+
+/// Returns the associated keys expired at the block time `now`.
+pub fn expired_keys(now: u64) -> Vec<AccountHash> {
+    match key_expiries() {
+        Some(key_expiries) => read_expiring_keys(key_expiries)
+            .into_iter()
+            .filter(|account_hash| {
+                read_expiry(key_expiries, *account_hash)
+                    .map_or(false, |expires_at| expires_at <= now)
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
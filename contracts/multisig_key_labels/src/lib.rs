@@ -0,0 +1,65 @@
+//! Human-readable labels for the associated keys of a multi-signature account.
+//!
+//! Labels are stored in the `multisig_key_labels` dictionary, owned by the account under the
+//! named key of the same name. Items are keyed by the formatted account hash of the associated
+//! key (`account-hash-...`) and hold an `Option<String>`, `None` once the key is removed.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::URef;
+
+pub const DICTIONARY_KEY_LABELS: &str = "multisig_key_labels";
+/// The maximum length of a label, in bytes.
+pub const MAX_LABEL_LENGTH: usize = 64;
+
+/// Returns whether `label` can be stored.
+pub fn is_valid_label(label: &str) -> bool {
+    !label.is_empty() && label.len() <= MAX_LABEL_LENGTH
+}
+
+fn key_labels() -> Option<URef> {
+    runtime::get_key(DICTIONARY_KEY_LABELS).map(|key| key.into_uref().unwrap_or_revert())
+}
+
+fn read_label(key_labels: URef, account_hash: AccountHash) -> Option<String> {
+    storage::dictionary_get::<Option<String>>(key_labels, &account_hash.to_formatted_string())
+        .unwrap_or_revert()
+        .flatten()
+}
+
+fn write_label(key_labels: URef, account_hash: AccountHash, label: Option<String>) {
+    storage::dictionary_put(key_labels, &account_hash.to_formatted_string(), label);
+}
+
+/// Labels the associated key `account_hash`, replacing any previous label.
+pub fn set_label(account_hash: AccountHash, label: String) {
+    let key_labels = match key_labels() {
+        Some(key_labels) => key_labels,
+        None => storage::new_dictionary(DICTIONARY_KEY_LABELS).unwrap_or_revert(),
+    };
+    write_label(key_labels, account_hash, Some(label));
+}
+
+/// Deletes the label of the associated key `account_hash`, if any.
+pub fn remove_label(account_hash: AccountHash) {
+    if let Some(key_labels) = key_labels() {
+        if read_label(key_labels, account_hash).is_some() {
+            write_label(key_labels, account_hash, None);
+        }
+    }
+}
+
+/// Moves the label of the associated key `old_account_hash` to `new_account_hash`, if any.
+pub fn move_label(old_account_hash: AccountHash, new_account_hash: AccountHash) {
+    if let Some(key_labels) = key_labels() {
+        if let Some(label) = read_label(key_labels, old_account_hash) {
+            write_label(key_labels, old_account_hash, None);
+            write_label(key_labels, new_account_hash, Some(label));
+        }
+    }
+}
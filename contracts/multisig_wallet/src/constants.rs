@@ -0,0 +1,22 @@
+pub const ENTRY_POINT_PROPOSE_TRANSFER: &str = "propose_transfer";
+pub const ENTRY_POINT_APPROVE: &str = "approve";
+pub const ENTRY_POINT_REVOKE_APPROVAL: &str = "revoke_approval";
+pub const ENTRY_POINT_EXECUTE: &str = "execute";
+
+pub const RUNTIME_ARG_SIGNERS: &str = "signers";
+pub const RUNTIME_ARG_THRESHOLD: &str = "threshold";
+pub const RUNTIME_ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const RUNTIME_ARG_TARGET: &str = "target";
+pub const RUNTIME_ARG_AMOUNT: &str = "amount";
+
+// Named keys of the installing account.
+pub const NAMED_KEY_CONTRACT_HASH: &str = "multisig_wallet_contract_hash";
+pub const NAMED_KEY_CONTRACT_PACKAGE_HASH: &str = "multisig_wallet_package_hash";
+pub const NAMED_KEY_DEPOSIT_PURSE: &str = "multisig_wallet_deposit_purse";
+
+// Named keys of the contract.
+pub const NAMED_KEY_PURSE: &str = "purse";
+pub const NAMED_KEY_SIGNERS: &str = "signers";
+pub const NAMED_KEY_THRESHOLD: &str = "threshold";
+pub const NAMED_KEY_PROPOSAL_COUNT: &str = "proposal_count";
+pub const DICTIONARY_PROPOSALS: &str = "multisig_wallet_proposals";
@@ -0,0 +1,174 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage, system};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::contracts::NamedKeys;
+use casper_types::{
+    CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
+    U512,
+};
+use multisig_common::errors::MultisigWalletError;
+use multisig_common::proposals::{
+    self, add_proposal, get_uref, read_named_value, read_proposal, write_proposal,
+};
+use multisig_wallet::constants::{
+    DICTIONARY_PROPOSALS, ENTRY_POINT_APPROVE, ENTRY_POINT_EXECUTE, ENTRY_POINT_PROPOSE_TRANSFER,
+    ENTRY_POINT_REVOKE_APPROVAL, NAMED_KEY_CONTRACT_HASH, NAMED_KEY_CONTRACT_PACKAGE_HASH,
+    NAMED_KEY_DEPOSIT_PURSE, NAMED_KEY_PROPOSAL_COUNT, NAMED_KEY_PURSE, NAMED_KEY_SIGNERS,
+    NAMED_KEY_THRESHOLD, RUNTIME_ARG_AMOUNT, RUNTIME_ARG_PROPOSAL_ID, RUNTIME_ARG_SIGNERS,
+    RUNTIME_ARG_TARGET, RUNTIME_ARG_THRESHOLD,
+};
+use multisig_wallet::proposal::Proposal;
+
+/// Returns the caller, reverting unless it is one of the signers.
+fn get_signer() -> AccountHash {
+    proposals::get_signer::<Vec<AccountHash>, _>(NAMED_KEY_SIGNERS, MultisigWalletError::NotASigner)
+}
+
+#[no_mangle]
+pub extern "C" fn propose_transfer() {
+    let proposer = get_signer();
+    let target: AccountHash = runtime::get_named_arg(RUNTIME_ARG_TARGET);
+    let amount: U512 = runtime::get_named_arg(RUNTIME_ARG_AMOUNT);
+    if amount.is_zero() {
+        runtime::revert(MultisigWalletError::InvalidAmount);
+    }
+
+    // Proposing a transfer counts as approving it.
+    let proposal_id = add_proposal(
+        NAMED_KEY_PROPOSAL_COUNT,
+        Proposal {
+            target,
+            amount,
+            approvals: vec![proposer],
+            executed: false,
+        },
+    );
+
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let signer = get_signer();
+    proposals::approve::<Proposal>(signer, runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID));
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_approval() {
+    let signer = get_signer();
+    proposals::revoke_approval::<Proposal>(signer, runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID));
+}
+
+#[no_mangle]
+pub extern "C" fn execute() {
+    get_signer();
+    let proposal_id: u64 = runtime::get_named_arg(RUNTIME_ARG_PROPOSAL_ID);
+    let mut proposal: Proposal = read_proposal(proposal_id);
+
+    let threshold: u8 = read_named_value(NAMED_KEY_THRESHOLD);
+    if proposal.approvals.len() < usize::from(threshold) {
+        runtime::revert(MultisigWalletError::InsufficientApprovals);
+    }
+
+    system::transfer_from_purse_to_account(
+        get_uref(NAMED_KEY_PURSE),
+        proposal.target,
+        proposal.amount,
+        Some(proposal_id),
+    )
+    .unwrap_or_revert();
+
+    proposal.executed = true;
+    write_proposal(proposal_id, proposal);
+}
+
+fn proposal_entry_point(name: &str) -> EntryPoint {
+    EntryPoint::new(
+        name,
+        vec![Parameter::new(RUNTIME_ARG_PROPOSAL_ID, CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        ENTRY_POINT_PROPOSE_TRANSFER,
+        vec![
+            Parameter::new(RUNTIME_ARG_TARGET, AccountHash::cl_type()),
+            Parameter::new(RUNTIME_ARG_AMOUNT, CLType::U512),
+        ],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(proposal_entry_point(ENTRY_POINT_APPROVE));
+    entry_points.add_entry_point(proposal_entry_point(ENTRY_POINT_REVOKE_APPROVAL));
+    entry_points.add_entry_point(proposal_entry_point(ENTRY_POINT_EXECUTE));
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    if runtime::has_key(NAMED_KEY_CONTRACT_HASH) {
+        runtime::revert(MultisigWalletError::AlreadyInstalled);
+    }
+
+    let signers: Vec<AccountHash> = runtime::get_named_arg(RUNTIME_ARG_SIGNERS);
+    let threshold: u8 = runtime::get_named_arg(RUNTIME_ARG_THRESHOLD);
+
+    let mut unique_signers = signers.clone();
+    unique_signers.sort_unstable();
+    unique_signers.dedup();
+    if signers.is_empty() || unique_signers.len() != signers.len() {
+        runtime::revert(MultisigWalletError::InvalidSigners);
+    }
+    if threshold == 0 || usize::from(threshold) > signers.len() {
+        runtime::revert(MultisigWalletError::InvalidThreshold);
+    }
+
+    let purse = system::create_purse();
+    // The dictionary is created in the account's context and handed over to the contract, so the
+    // account must not keep its own reference to it.
+    let proposals = storage::new_dictionary(DICTIONARY_PROPOSALS).unwrap_or_revert();
+    runtime::remove_key(DICTIONARY_PROPOSALS);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(NAMED_KEY_PURSE), purse.into());
+    named_keys.insert(
+        String::from(NAMED_KEY_SIGNERS),
+        storage::new_uref(signers).into(),
+    );
+    named_keys.insert(
+        String::from(NAMED_KEY_THRESHOLD),
+        storage::new_uref(threshold).into(),
+    );
+    named_keys.insert(
+        String::from(NAMED_KEY_PROPOSAL_COUNT),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(String::from(DICTIONARY_PROPOSALS), proposals.into());
+
+    // A locked contract cannot be upgraded, so no single key can bypass the signers.
+    let (contract_hash, _) = storage::new_locked_contract(
+        entry_points(),
+        Some(named_keys),
+        Some(String::from(NAMED_KEY_CONTRACT_PACKAGE_HASH)),
+        None,
+    );
+    runtime::put_key(NAMED_KEY_CONTRACT_HASH, contract_hash.into());
+    runtime::put_key(NAMED_KEY_DEPOSIT_PURSE, purse.into_add().into());
+}
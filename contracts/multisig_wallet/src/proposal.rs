@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+use casper_types::account::AccountHash;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped, U512};
+use multisig_common::errors::MultisigWalletError;
+use multisig_common::proposals;
+
+use crate::constants::DICTIONARY_PROPOSALS;
+
+/// A transfer out of the wallet purse, waiting for the approvals of the signers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    pub target: AccountHash,
+    pub amount: U512,
+    /// The signers approving the transfer, the proposer first.
+    pub approvals: Vec<AccountHash>,
+    pub executed: bool,
+}
+
+/// Typed as nested tuples of the fields in order, which serialize to the same bytes.
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        <((AccountHash, U512), Vec<AccountHash>, bool)>::cl_type()
+    }
+}
+
+impl proposals::Proposal for Proposal {
+    type Error = MultisigWalletError;
+
+    const DICTIONARY: &'static str = DICTIONARY_PROPOSALS;
+    const MISSING_PROPOSAL: MultisigWalletError = MultisigWalletError::MissingProposal;
+    const CLOSED: MultisigWalletError = MultisigWalletError::AlreadyExecuted;
+    const ALREADY_APPROVED: MultisigWalletError = MultisigWalletError::AlreadyApproved;
+    const NOT_APPROVED: MultisigWalletError = MultisigWalletError::NotApproved;
+
+    fn approvals(&mut self) -> &mut Vec<AccountHash> {
+        &mut self.approvals
+    }
+
+    fn is_closed(&self) -> bool {
+        self.executed
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.target.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.target.serialized_length()
+            + self.amount.serialized_length()
+            + self.approvals.serialized_length()
+            + self.executed.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (target, remainder) = AccountHash::from_bytes(bytes)?;
+        let (amount, remainder) = U512::from_bytes(remainder)?;
+        let (approvals, remainder) = Vec::<AccountHash>::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let proposal = Proposal {
+            target,
+            amount,
+            approvals,
+            executed,
+        };
+        Ok((proposal, remainder))
+    }
+}
@@ -0,0 +1,5 @@
+pub const RUNTIME_ARG_DELAY: &str = "delay";
+pub const RUNTIME_ARG_CHANGE_ID: &str = "change_id";
+
+/// The shortest delay, in milliseconds, between queueing a change and executing it.
+pub const MIN_DELAY: u64 = 24 * 60 * 60 * 1000;
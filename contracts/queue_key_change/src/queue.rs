@@ -0,0 +1,99 @@
+//! Key changes queued for execution after a delay.
+//!
+//! Changes are stored in the `multisig_queued_key_changes` dictionary, owned by the account under
+//! the named key of the same name. The `length` item holds the number of changes ever queued, a
+//! `u64`, and items `0`, `1`, ... hold an `Option<QueuedKeyChange>`, `None` once the change was
+//! executed or cancelled.
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped, URef};
+use key_manager::operation::KeyOperation;
+
+pub const DICTIONARY_QUEUED_KEY_CHANGES: &str = "multisig_queued_key_changes";
+pub const QUEUED_KEY_CHANGES_LENGTH: &str = "length";
+
+/// A key change waiting for its delay to pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueuedKeyChange {
+    pub change: KeyOperation,
+    /// The block time in milliseconds from which the change can be executed.
+    pub execute_after: u64,
+}
+
+/// Typed as a tuple of the fields in order, which serializes to the same bytes.
+impl CLTyped for QueuedKeyChange {
+    fn cl_type() -> CLType {
+        <(KeyOperation, u64)>::cl_type()
+    }
+}
+
+impl ToBytes for QueuedKeyChange {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.change.to_bytes()?);
+        result.append(&mut self.execute_after.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.change.serialized_length() + self.execute_after.serialized_length()
+    }
+}
+
+impl FromBytes for QueuedKeyChange {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (change, remainder) = KeyOperation::from_bytes(bytes)?;
+        let (execute_after, remainder) = u64::from_bytes(remainder)?;
+        let queued_change = QueuedKeyChange {
+            change,
+            execute_after,
+        };
+        Ok((queued_change, remainder))
+    }
+}
+
+fn queued_key_changes() -> Option<URef> {
+    runtime::get_key(DICTIONARY_QUEUED_KEY_CHANGES).map(|key| key.into_uref().unwrap_or_revert())
+}
+
+/// Queues `change` for execution from the block time `execute_after`, returning its id.
+pub fn queue_change(change: KeyOperation, execute_after: u64) -> u64 {
+    let queued_key_changes = match queued_key_changes() {
+        Some(queued_key_changes) => queued_key_changes,
+        None => storage::new_dictionary(DICTIONARY_QUEUED_KEY_CHANGES).unwrap_or_revert(),
+    };
+
+    let change_id: u64 = storage::dictionary_get(queued_key_changes, QUEUED_KEY_CHANGES_LENGTH)
+        .unwrap_or_revert()
+        .unwrap_or_default();
+    storage::dictionary_put(
+        queued_key_changes,
+        &change_id.to_string(),
+        Some(QueuedKeyChange {
+            change,
+            execute_after,
+        }),
+    );
+    storage::dictionary_put(queued_key_changes, QUEUED_KEY_CHANGES_LENGTH, change_id + 1);
+    change_id
+}
+
+/// Removes the change `change_id` from the queue, returning it if it was still queued.
+pub fn take_queued_change(change_id: u64) -> Option<QueuedKeyChange> {
+    let queued_key_changes = queued_key_changes()?;
+    let queued_change = storage::dictionary_get::<Option<QueuedKeyChange>>(
+        queued_key_changes,
+        &change_id.to_string(),
+    )
+    .unwrap_or_revert()
+    .flatten()?;
+    storage::dictionary_put(
+        queued_key_changes,
+        &change_id.to_string(),
+        Option::<QueuedKeyChange>::None,
+    );
+    Some(queued_change)
+}
@@ -0,0 +1,72 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::runtime;
+use key_manager::operation::KeyOperation;
+use multisig_common::args::has_named_arg;
+use multisig_common::constants::{
+    RUNTIME_ARG_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD,
+    RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH, RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT,
+    RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH,
+};
+use multisig_common::errors::QueueKeyChangeError;
+use queue_key_change::constants::{MIN_DELAY, RUNTIME_ARG_DELAY};
+use queue_key_change::queue::queue_change;
+
+/// Reads the change from whichever of the add, remove or thresholds arguments were supplied,
+/// using the argument names of `add_account`, `remove_account` and `update_thresholds`.
+fn get_key_change() -> KeyOperation {
+    let is_add = has_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH);
+    let is_remove = has_named_arg(RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH);
+    let is_thresholds = has_named_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD)
+        || has_named_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD);
+
+    match (is_add, is_remove, is_thresholds) {
+        (true, false, false) => {
+            let weight: u8 = runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT);
+            if weight == 0 {
+                runtime::revert(QueueKeyChangeError::ZeroWeight);
+            }
+            KeyOperation::Add {
+                account: runtime::get_named_arg(RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH),
+                weight,
+            }
+        }
+        (false, true, false) => KeyOperation::Remove {
+            account: runtime::get_named_arg(RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH),
+        },
+        (false, false, true) => {
+            // Both thresholds are queued, as the other one may change during the delay.
+            let deployment: u8 = runtime::get_named_arg(RUNTIME_ARG_DEPLOYMENT_THRESHOLD);
+            let key_management: u8 = runtime::get_named_arg(RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD);
+            if deployment > key_management {
+                runtime::revert(QueueKeyChangeError::InvalidThresholds);
+            }
+            KeyOperation::SetThresholds {
+                deployment: Some(deployment),
+                key_management: Some(key_management),
+            }
+        }
+        _ => runtime::revert(QueueKeyChangeError::InvalidChange),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let change = get_key_change();
+
+    let delay: u64 = if has_named_arg(RUNTIME_ARG_DELAY) {
+        runtime::get_named_arg(RUNTIME_ARG_DELAY)
+    } else {
+        MIN_DELAY
+    };
+    if delay < MIN_DELAY {
+        runtime::revert(QueueKeyChangeError::DelayTooShort);
+    }
+
+    let now: u64 = runtime::get_blocktime().into();
+    queue_change(change, now.saturating_add(delay));
+}
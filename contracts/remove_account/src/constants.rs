@@ -1 +0,0 @@
-pub const RUNTIME_ARG_REMOVE_ASSOCIATED_KEY: &str = "remove_key";
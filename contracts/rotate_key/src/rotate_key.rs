@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use key_manager::session;
+
+/// Runs the `rotate` operation of `key_manager`, kept for existing clients.
+#[no_mangle]
+pub extern "C" fn call() {
+    session::rotate();
+}
@@ -0,0 +1,37 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::runtime;
+use casper_types::U512;
+use multisig_common::errors::SetSpendingLimitError;
+use transfer::constants::{RUNTIME_ARG_SPENDING_LIMIT, RUNTIME_ARG_SPENDING_PERIOD};
+use transfer::limit::{has_key_management_weight, write_spending_limit, SpendingLimit};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let limit: U512 = runtime::get_named_arg(RUNTIME_ARG_SPENDING_LIMIT);
+    let period: u64 = runtime::get_named_arg(RUNTIME_ARG_SPENDING_PERIOD);
+
+    if period == 0 {
+        runtime::revert(SetSpendingLimitError::InvalidPeriod);
+    }
+
+    // The limit is only honoured by `transfer.wasm`: a deploy meeting the deployment threshold can
+    // still overwrite the named key or transfer with other session code. Setting it here takes
+    // the weight it lets `transfer.wasm` go without.
+    match has_key_management_weight() {
+        Some(true) => {}
+        Some(false) => runtime::revert(SetSpendingLimitError::PermissionDenied),
+        None => runtime::revert(SetSpendingLimitError::MaxKeysLimit),
+    }
+
+    write_spending_limit(SpendingLimit {
+        limit,
+        period,
+        period_start: runtime::get_blocktime().into(),
+        spent: U512::zero(),
+    });
+}
@@ -0,0 +1 @@
+pub const NAMED_KEY_ACCOUNT_SNAPSHOT: &str = "multisig_account_snapshot";
@@ -0,0 +1,51 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::{account, runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::{ActionType, SetThresholdFailure, Weight};
+use key_manager::probes::{check_thresholds, saturated_weight, total_key_weight};
+use key_manager::thresholds::revert_with;
+use multisig_audit_log::recorded_configuration;
+use multisig_common::errors::SnapshotAccountError;
+use snapshot_account::constants::NAMED_KEY_ACCOUNT_SNAPSHOT;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // Session code cannot read the account record, so the snapshot is the configuration recorded
+    // by the audit log, complete once `configure_multisig.wasm` has declared every key.
+    let configuration =
+        recorded_configuration().unwrap_or_revert_with(SnapshotAccountError::Unconfigured);
+
+    // Changes made by other session code are not recorded, so the thresholds and the total key
+    // weight are checked against the host before anything is written. This moves key management
+    // through other values before restoring it.
+    let (keys, deployment_threshold, key_mgmt_threshold) = &configuration;
+    check_thresholds(
+        *deployment_threshold,
+        *key_mgmt_threshold,
+        SnapshotAccountError::Mismatch,
+    );
+    if total_key_weight(*deployment_threshold) != saturated_weight(keys) {
+        runtime::revert(SnapshotAccountError::Mismatch);
+    }
+    match account::set_action_threshold(ActionType::KeyManagement, Weight::new(*key_mgmt_threshold))
+    {
+        Ok(()) => {}
+        Err(SetThresholdFailure::InsufficientTotalWeight) => {
+            runtime::revert(SnapshotAccountError::Mismatch)
+        }
+        Err(failure) => revert_with(failure),
+    }
+
+    match runtime::get_key(NAMED_KEY_ACCOUNT_SNAPSHOT) {
+        Some(key) => storage::write(key.into_uref().unwrap_or_revert(), configuration),
+        None => runtime::put_key(
+            NAMED_KEY_ACCOUNT_SNAPSHOT,
+            storage::new_uref(configuration).into(),
+        ),
+    }
+}
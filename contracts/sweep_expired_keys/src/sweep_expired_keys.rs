@@ -0,0 +1,33 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::runtime;
+use casper_types::account::RemoveKeyFailure;
+use key_manager::keys::try_remove_key;
+use multisig_common::errors::SweepExpiredKeysError;
+use multisig_key_expiries::{clear_expiry, expired_keys};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let now: u64 = runtime::get_blocktime().into();
+
+    for account_hash in expired_keys(now) {
+        match try_remove_key(account_hash) {
+            Ok(()) => {}
+            // The key was already removed by other session code, only its expiry is left.
+            Err(RemoveKeyFailure::MissingKey) => clear_expiry(account_hash),
+            Err(RemoveKeyFailure::PermissionDenied) => {
+                runtime::revert(SweepExpiredKeysError::PermissionDenied)
+            }
+            // Reverting undoes the removals made so far, so either every expired key is removed
+            // or none is.
+            Err(RemoveKeyFailure::ThresholdViolation) => {
+                runtime::revert(SweepExpiredKeysError::LockoutRisk)
+            }
+            Err(failure) => runtime::revert(failure),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub const RUNTIME_ARG_TARGET: &str = "target";
+pub const RUNTIME_ARG_AMOUNT: &str = "amount";
+pub const RUNTIME_ARG_TRANSFER_ID: &str = "transfer_id";
+pub const RUNTIME_ARG_SPENDING_LIMIT: &str = "spending_limit";
+pub const RUNTIME_ARG_SPENDING_PERIOD: &str = "spending_period";
+
+pub const NAMED_KEY_SPENDING_LIMIT: &str = "multisig_spending_limit";
@@ -0,0 +1,131 @@
+//! The spending limit of the `transfer` session code and the key management weight check that
+//! lifts it.
+//!
+//! The limit is stored as a [`SpendingLimit`] under the `multisig_spending_limit` named key of the
+//! account. Without one, every transfer needs key management weight.
+//!
+//! The limit is advisory: only `transfer.wasm` enforces it. Any deploy meeting the deployment
+//! threshold can send tokens from the main purse with other session code, or overwrite the named
+//! key.
+
+use alloc::vec::Vec;
+use casper_contract::contract_api::{account, runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::{AccountHash, AddKeyFailure, Weight};
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{CLType, CLTyped, U512};
+
+use crate::constants::NAMED_KEY_SPENDING_LIMIT;
+
+/// Transfers allowed with deployment weight alone, over fixed periods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpendingLimit {
+    pub limit: U512,
+    /// The length of a period in milliseconds.
+    pub period: u64,
+    /// The block time at which the current period started.
+    pub period_start: u64,
+    /// The amount transferred under the limit during the current period.
+    pub spent: U512,
+}
+
+impl SpendingLimit {
+    /// Returns the limit as seen at the block time `now`, starting a new period if the current
+    /// one is over.
+    pub fn at(self, now: u64) -> SpendingLimit {
+        if now >= self.period_start.saturating_add(self.period) {
+            SpendingLimit {
+                period_start: now,
+                spent: U512::zero(),
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Returns the limit with `spent` transferred during the current period.
+    pub fn with_spent(self, spent: U512) -> SpendingLimit {
+        SpendingLimit { spent, ..self }
+    }
+}
+
+/// Typed as nested tuples of the fields in order, which serialize to the same bytes.
+impl CLTyped for SpendingLimit {
+    fn cl_type() -> CLType {
+        <((U512, u64), (u64, U512))>::cl_type()
+    }
+}
+
+impl ToBytes for SpendingLimit {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.limit.to_bytes()?);
+        result.append(&mut self.period.to_bytes()?);
+        result.append(&mut self.period_start.to_bytes()?);
+        result.append(&mut self.spent.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.limit.serialized_length()
+            + self.period.serialized_length()
+            + self.period_start.serialized_length()
+            + self.spent.serialized_length()
+    }
+}
+
+impl FromBytes for SpendingLimit {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (limit, remainder) = U512::from_bytes(bytes)?;
+        let (period, remainder) = u64::from_bytes(remainder)?;
+        let (period_start, remainder) = u64::from_bytes(remainder)?;
+        let (spent, remainder) = U512::from_bytes(remainder)?;
+        let spending_limit = SpendingLimit {
+            limit,
+            period,
+            period_start,
+            spent,
+        };
+        Ok((spending_limit, remainder))
+    }
+}
+
+/// Reads the spending limit of the account, if one was set.
+pub fn read_spending_limit() -> Option<SpendingLimit> {
+    let uref = runtime::get_key(NAMED_KEY_SPENDING_LIMIT)?
+        .into_uref()
+        .unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert()
+}
+
+/// Stores the spending limit of the account.
+pub fn write_spending_limit(spending_limit: SpendingLimit) {
+    match runtime::get_key(NAMED_KEY_SPENDING_LIMIT) {
+        Some(key) => storage::write(key.into_uref().unwrap_or_revert(), spending_limit),
+        None => runtime::put_key(
+            NAMED_KEY_SPENDING_LIMIT,
+            storage::new_uref(spending_limit).into(),
+        ),
+    }
+}
+
+/// Returns whether the keys authorizing the deploy carry key management weight, or `None` if the
+/// account has no room left to check it.
+///
+/// Session code can list the authorization keys with `runtime::list_authorization_keys`, but not
+/// the weights of the associated keys nor the thresholds. The host weighs the authorization keys
+/// against the associated keys whenever the account's keys change, so the check adds and removes
+/// a probe key no one holds, leaving the keys as they were.
+pub fn has_key_management_weight() -> Option<bool> {
+    let probe = AccountHash::new(runtime::blake2b(b"multisig key management weight probe"));
+    match account::add_associated_key(probe, Weight::new(1)) {
+        Ok(()) => {
+            account::remove_associated_key(probe).unwrap_or_revert();
+            Some(true)
+        }
+        Err(AddKeyFailure::PermissionDenied) => Some(false),
+        Err(AddKeyFailure::MaxKeysLimit) => None,
+        Err(failure) => runtime::revert(failure),
+    }
+}
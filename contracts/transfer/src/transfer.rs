@@ -0,0 +1,56 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::{runtime, system};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::AccountHash;
+use casper_types::U512;
+use multisig_common::args::has_named_arg;
+use multisig_common::errors::TransferError;
+use transfer::constants::{RUNTIME_ARG_AMOUNT, RUNTIME_ARG_TARGET, RUNTIME_ARG_TRANSFER_ID};
+use transfer::limit::{has_key_management_weight, read_spending_limit, write_spending_limit};
+
+/// Counts the transfer against the spending limit, returning whether it fits.
+fn spend_under_limit(amount: U512) -> bool {
+    let spending_limit = match read_spending_limit() {
+        Some(spending_limit) => spending_limit.at(runtime::get_blocktime().into()),
+        None => return false,
+    };
+
+    match spending_limit.spent.checked_add(amount) {
+        Some(spent) if spent <= spending_limit.limit => {
+            write_spending_limit(spending_limit.with_spent(spent));
+            true
+        }
+        _ => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let target: AccountHash = runtime::get_named_arg(RUNTIME_ARG_TARGET);
+    let amount: U512 = runtime::get_named_arg(RUNTIME_ARG_AMOUNT);
+    let transfer_id = if has_named_arg(RUNTIME_ARG_TRANSFER_ID) {
+        Some(runtime::get_named_arg::<u64>(RUNTIME_ARG_TRANSFER_ID))
+    } else {
+        None
+    };
+
+    if amount.is_zero() {
+        runtime::revert(TransferError::InvalidAmount);
+    }
+
+    // Transfers above the limit do not use it up.
+    if !spend_under_limit(amount) {
+        match has_key_management_weight() {
+            Some(true) => {}
+            Some(false) => runtime::revert(TransferError::LimitExceeded),
+            None => runtime::revert(TransferError::MaxKeysLimit),
+        }
+    }
+
+    system::transfer_to_account(target, amount, transfer_id).unwrap_or_revert();
+}
@@ -0,0 +1,35 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::{runtime, system};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::system::auction;
+use casper_types::{runtime_args, PublicKey, RuntimeArgs, U512};
+use delegate::constants::{RUNTIME_ARG_AMOUNT, RUNTIME_ARG_VALIDATOR};
+use delegate::delegation::get_delegator;
+use multisig_common::errors::UndelegateError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let delegator = get_delegator().unwrap_or_revert_with(UndelegateError::InvalidDelegator);
+    let validator: PublicKey = runtime::get_named_arg(RUNTIME_ARG_VALIDATOR);
+    let amount: U512 = runtime::get_named_arg(RUNTIME_ARG_AMOUNT);
+
+    if amount.is_zero() {
+        runtime::revert(UndelegateError::InvalidAmount);
+    }
+
+    // The amount returns to the main purse of the account after the unbonding delay.
+    runtime::call_contract::<U512>(
+        system::get_auction(),
+        auction::METHOD_UNDELEGATE,
+        runtime_args! {
+            auction::ARG_DELEGATOR => delegator,
+            auction::ARG_VALIDATOR => validator,
+            auction::ARG_AMOUNT => amount,
+        },
+    );
+}
@@ -0,0 +1,30 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::account::Weight;
+use freeze_account::constants::NAMED_KEY_FROZEN_THRESHOLDS;
+use key_manager::thresholds::set_thresholds;
+use multisig_audit_log::record_threshold_change;
+use multisig_common::errors::UnfreezeAccountError;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let frozen_thresholds = runtime::get_key(NAMED_KEY_FROZEN_THRESHOLDS)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert_with(UnfreezeAccountError::NotFrozen);
+    let (deployment_threshold, key_mgmt_threshold): (u8, u8) = storage::read(frozen_thresholds)
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(UnfreezeAccountError::NotFrozen);
+
+    set_thresholds(
+        Weight::new(deployment_threshold),
+        Weight::new(key_mgmt_threshold),
+    );
+    record_threshold_change(Some(deployment_threshold), Some(key_mgmt_threshold));
+    runtime::remove_key(NAMED_KEY_FROZEN_THRESHOLDS);
+}
@@ -1,2 +0,0 @@
-pub const RUNTIME_ARG_ASSOCIATED_KEY: &str = "associated_key";
-pub const RUNTIME_ARG_NEW_KEY_WEIGHT: &str = "new_weight";
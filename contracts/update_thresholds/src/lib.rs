@@ -1,4 +0,0 @@
-#![no_std]
-
-pub mod constants;
-pub mod errors;
@@ -1,7 +1,25 @@
 use casper_types::account::AccountHash;
 
 pub const ADD_ACCOUNT_WASM: &str = "add_account.wasm";
+pub const APPLY_KEY_CHANGE_WASM: &str = "apply_key_change.wasm";
+pub const CANCEL_KEY_CHANGE_WASM: &str = "cancel_key_change.wasm";
+pub const CONFIGURE_MULTISIG_WASM: &str = "configure_multisig.wasm";
+pub const DELEGATE_WASM: &str = "delegate.wasm";
+pub const EXECUTE_KEY_CHANGE_WASM: &str = "execute_key_change.wasm";
+pub const FREEZE_ACCOUNT_WASM: &str = "freeze_account.wasm";
+pub const KEY_CHANGE_PROPOSALS_WASM: &str = "key_change_proposals.wasm";
+pub const KEY_MANAGER_WASM: &str = "key_manager.wasm";
+pub const MULTISIG_WALLET_WASM: &str = "multisig_wallet.wasm";
+pub const QUEUE_KEY_CHANGE_WASM: &str = "queue_key_change.wasm";
+pub const REDELEGATE_WASM: &str = "redelegate.wasm";
 pub const REMOVE_ACCOUNT_WASM: &str = "remove_account.wasm";
+pub const ROTATE_KEY_WASM: &str = "rotate_key.wasm";
+pub const SET_SPENDING_LIMIT_WASM: &str = "set_spending_limit.wasm";
+pub const SNAPSHOT_ACCOUNT_WASM: &str = "snapshot_account.wasm";
+pub const SWEEP_EXPIRED_KEYS_WASM: &str = "sweep_expired_keys.wasm";
+pub const TRANSFER_WASM: &str = "transfer.wasm";
+pub const UNDELEGATE_WASM: &str = "undelegate.wasm";
+pub const UNFREEZE_ACCOUNT_WASM: &str = "unfreeze_account.wasm";
 pub const UPDATE_KEYS_WASM: &str = "update_associated_keys.wasm";
 pub const UPDATE_THRESHOLDS_WASM: &str = "update_thresholds.wasm";
 pub const CONTRACT_WASM: &str = "contract.wasm";
@@ -12,3 +30,4 @@ pub const RUNTIME_ARG_NAME: &str = "message";
 
 pub const USER_1_ACCOUNT: AccountHash = AccountHash::new([1u8; 32]);
 pub const USER_2_ACCOUNT: AccountHash = AccountHash::new([2u8; 32]);
+pub const USER_3_ACCOUNT: AccountHash = AccountHash::new([3u8; 32]);
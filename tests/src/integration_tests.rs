@@ -1,28 +1,479 @@
 #[cfg(test)]
 mod tests {
-    use casper_execution_engine::core::{engine_state::Error as EngineStateError, execution};
+    use casper_execution_engine::core::{
+        engine_state::{Error as EngineStateError, ExecuteRequest},
+        execution,
+    };
+    use casper_execution_engine::shared::{additive_map::AdditiveMap, transform::Transform};
+    use std::collections::BTreeMap;
     use std::path::PathBuf;
 
-    use add_account::constants::{
-        RUNTIME_ARG_NEW_ASSOCIATED_KEY, RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT,
-    };
     use casper_engine_test_support::{
         DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
-        DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+        DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_PAYMENT,
+        DEFAULT_PROPOSER_PUBLIC_KEY, PRODUCTION_RUN_GENESIS_REQUEST,
     };
-    use casper_types::{account::Weight, runtime_args, ApiError, Key, RuntimeArgs};
-    use remove_account::constants::RUNTIME_ARG_REMOVE_ASSOCIATED_KEY;
-    use tests::constants::{
-        ADD_ACCOUNT_WASM, CONTRACT_WASM, KEY_NAME, REMOVE_ACCOUNT_WASM, RUNTIME_ARG_NAME,
-        TEST_VALUE, UPDATE_KEYS_WASM, UPDATE_THRESHOLDS_WASM, USER_1_ACCOUNT, USER_2_ACCOUNT,
+    use casper_types::{
+        account::{Account, AccountHash, ActionType, Weight},
+        bytesrepr::FromBytes,
+        runtime_args,
+        system::{auction, mint},
+        ApiError, CLTyped, CLValue, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey,
+        StoredValue, U512,
+    };
+    use configure_multisig::constants::{
+        RUNTIME_ARG_ASSOCIATED_KEYS, RUNTIME_ARG_PRIMARY_KEY_WEIGHT,
+    };
+    use delegate::constants::{
+        RUNTIME_ARG_AMOUNT as RUNTIME_ARG_DELEGATION_AMOUNT, RUNTIME_ARG_DELEGATOR,
+        RUNTIME_ARG_NEW_VALIDATOR, RUNTIME_ARG_VALIDATOR,
+    };
+    use freeze_account::constants::NAMED_KEY_FROZEN_THRESHOLDS;
+    use key_change_proposals::constants as key_change;
+    use key_manager::operation::KeyOperation;
+    use multisig_audit_log::{
+        AuditRecord, Operation, ValueChange, AUDIT_LOG_LENGTH, DICTIONARY_AUDIT_LOG,
+    };
+    use multisig_common::constants::{
+        OPERATION_ADD, OPERATION_REMOVE, OPERATION_UPDATE_WEIGHT,
+        RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH, RUNTIME_ARG_ASSOCIATED_KEY,
+        RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT, RUNTIME_ARG_DEPLOYMENT_THRESHOLD,
+        RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD, RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH,
+        RUNTIME_ARG_NEW_ASSOCIATED_KEY, RUNTIME_ARG_NEW_ASSOCIATED_KEYS,
+        RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY, RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL,
+        RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT, RUNTIME_ARG_NEW_ASSOCIATED_PUBLIC_KEY,
+        RUNTIME_ARG_NEW_KEY_WEIGHT, RUNTIME_ARG_OLD_ASSOCIATED_KEY, RUNTIME_ARG_OPERATION,
+        RUNTIME_ARG_OPERATIONS, RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH,
+        RUNTIME_ARG_REMOVE_ASSOCIATED_KEY, RUNTIME_ARG_REMOVE_ASSOCIATED_PUBLIC_KEY,
+        RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY,
+    };
+    use multisig_common::errors::{
+        user_error_message, AddAccountError, ApplyKeyChangeError, CancelKeyChangeError,
+        ConfigureMultisigError, DelegateError, ExecuteKeyChangeError, FreezeAccountError,
+        KeyChangeProposalsError, KeyManagerError, MultisigWalletError, QueueKeyChangeError,
+        RedelegateError, RemoveAccountError, RotateKeyError, SetSpendingLimitError,
+        SnapshotAccountError, SweepExpiredKeysError, TransferError, UndelegateError,
+        UnfreezeAccountError, UpdateAssociatedKeysError, UpdateThresholdsError,
     };
-    use update_associated_keys::constants::{
-        RUNTIME_ARG_ASSOCIATED_KEY, RUNTIME_ARG_NEW_KEY_WEIGHT,
+    use multisig_key_labels::DICTIONARY_KEY_LABELS;
+    use multisig_wallet::constants::{
+        DICTIONARY_PROPOSALS, ENTRY_POINT_APPROVE, ENTRY_POINT_EXECUTE,
+        ENTRY_POINT_PROPOSE_TRANSFER, ENTRY_POINT_REVOKE_APPROVAL, NAMED_KEY_CONTRACT_HASH,
+        NAMED_KEY_DEPOSIT_PURSE, RUNTIME_ARG_AMOUNT, RUNTIME_ARG_PROPOSAL_ID, RUNTIME_ARG_SIGNERS,
+        RUNTIME_ARG_TARGET, RUNTIME_ARG_THRESHOLD,
     };
-    use update_thresholds::constants::{
-        RUNTIME_ARG_NEW_DEPLOYMENT_THRESHOLD, RUNTIME_ARG_NEW_KEY_MANAGEMENT_THRESHOLD,
+    use multisig_wallet::proposal::Proposal;
+    use queue_key_change::constants::{MIN_DELAY, RUNTIME_ARG_CHANGE_ID, RUNTIME_ARG_DELAY};
+    use snapshot_account::constants::NAMED_KEY_ACCOUNT_SNAPSHOT;
+    use tests::constants::{
+        ADD_ACCOUNT_WASM, APPLY_KEY_CHANGE_WASM, CANCEL_KEY_CHANGE_WASM, CONFIGURE_MULTISIG_WASM,
+        CONTRACT_WASM, DELEGATE_WASM, EXECUTE_KEY_CHANGE_WASM, FREEZE_ACCOUNT_WASM,
+        KEY_CHANGE_PROPOSALS_WASM, KEY_MANAGER_WASM, KEY_NAME, MULTISIG_WALLET_WASM,
+        QUEUE_KEY_CHANGE_WASM, REDELEGATE_WASM, REMOVE_ACCOUNT_WASM, ROTATE_KEY_WASM,
+        RUNTIME_ARG_NAME, SET_SPENDING_LIMIT_WASM, SNAPSHOT_ACCOUNT_WASM, SWEEP_EXPIRED_KEYS_WASM,
+        TEST_VALUE, TRANSFER_WASM, UNDELEGATE_WASM, UNFREEZE_ACCOUNT_WASM, UPDATE_KEYS_WASM,
+        UPDATE_THRESHOLDS_WASM, USER_1_ACCOUNT, USER_2_ACCOUNT, USER_3_ACCOUNT,
+    };
+    use transfer::constants::{
+        RUNTIME_ARG_SPENDING_LIMIT, RUNTIME_ARG_SPENDING_PERIOD, RUNTIME_ARG_TRANSFER_ID,
     };
 
+    fn assert_reverted_with(builder: &InMemoryWasmTestBuilder, expected_error: ApiError) {
+        let actual_error = builder.get_error().expect("must have error");
+        assert!(
+            matches!(
+                actual_error,
+                EngineStateError::Exec(execution::Error::Revert(api_error)) if api_error == expected_error
+            ),
+            "Expected {:?}, received {:?}",
+            EngineStateError::Exec(execution::Error::Revert(expected_error)),
+            actual_error
+        );
+    }
+
+    fn query_audit_log<T: CLTyped + FromBytes>(
+        builder: &InMemoryWasmTestBuilder,
+        item_key: &str,
+    ) -> T {
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let audit_log = account
+            .named_keys()
+            .get(DICTIONARY_AUDIT_LOG)
+            .and_then(Key::as_uref)
+            .expect("should have an audit log");
+
+        builder
+            .query(None, Key::dictionary(*audit_log, item_key.as_bytes()), &[])
+            .expect("should have the audit log item")
+            .as_cl_value()
+            .cloned()
+            .expect("should be a CLValue")
+            .into_t()
+            .expect("should have the expected type")
+    }
+
+    /// Lists the associated keys of the default account with their labels.
+    fn list_key_labels(builder: &InMemoryWasmTestBuilder) -> BTreeMap<AccountHash, Option<String>> {
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let key_labels = account
+            .named_keys()
+            .get(DICTIONARY_KEY_LABELS)
+            .and_then(Key::as_uref);
+
+        account
+            .associated_keys()
+            .iter()
+            .map(|(account_hash, _)| {
+                let label = key_labels.and_then(|key_labels| {
+                    let item_key = account_hash.to_formatted_string();
+                    builder
+                        .query(None, Key::dictionary(*key_labels, item_key.as_bytes()), &[])
+                        .ok()?
+                        .as_cl_value()?
+                        .clone()
+                        .into_t::<Option<String>>()
+                        .expect("should be an optional label")
+                });
+                (*account_hash, label)
+            })
+            .collect()
+    }
+
+    /// Configures the default account with `configure_multisig.wasm`, which records the whole
+    /// configuration in the audit log.
+    fn configure_default_account(
+        builder: &mut InMemoryWasmTestBuilder,
+        primary_key_weight: u8,
+        associated_keys: &[(AccountHash, u8)],
+        deployment_threshold: u8,
+        key_mgmt_threshold: u8,
+    ) {
+        let associated_keys: BTreeMap<Key, u8> = associated_keys
+            .iter()
+            .map(|(account_hash, weight)| (Key::from(*account_hash), *weight))
+            .collect();
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => primary_key_weight,
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => deployment_threshold,
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => key_mgmt_threshold,
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+    }
+
+    /// Changes the default account without running any session code, as session code that does not
+    /// record its changes in the audit log would.
+    fn change_account_outside_contracts<F: FnOnce(&mut Account)>(
+        builder: &mut InMemoryWasmTestBuilder,
+        change: F,
+    ) {
+        let mut account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+        change(&mut account);
+
+        let mut effects = AdditiveMap::new();
+        effects.insert(
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            Transform::Write(StoredValue::Account(account)),
+        );
+        let post_state_hash = builder.get_post_state_hash();
+        builder.commit_transforms(post_state_hash, effects);
+    }
+
+    fn fund_account(builder: &mut InMemoryWasmTestBuilder, account: AccountHash) {
+        let transfer_request = ExecuteRequestBuilder::transfer(
+            *DEFAULT_ACCOUNT_ADDR,
+            runtime_args! {
+                mint::ARG_AMOUNT => U512::from(100_000_000_000_000u64),
+                mint::ARG_TARGET => account,
+                mint::ARG_ID => Option::<u64>::None,
+            },
+        )
+        .build();
+
+        builder.exec(transfer_request).expect_success().commit();
+    }
+
+    fn install_multisig_wallet(
+        builder: &mut InMemoryWasmTestBuilder,
+        signers: Vec<AccountHash>,
+        threshold: u8,
+    ) -> ContractHash {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MULTISIG_WALLET_WASM,
+            runtime_args! {
+                RUNTIME_ARG_SIGNERS => signers,
+                RUNTIME_ARG_THRESHOLD => threshold,
+            },
+        )
+        .build();
+
+        builder.exec(install_request).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        account
+            .named_keys()
+            .get(NAMED_KEY_CONTRACT_HASH)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+            .expect("should have the wallet contract hash")
+    }
+
+    fn call_stored_contract<'a>(
+        builder: &'a mut InMemoryWasmTestBuilder,
+        caller: AccountHash,
+        contract_hash: ContractHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> &'a mut InMemoryWasmTestBuilder {
+        let call_request =
+            ExecuteRequestBuilder::contract_call_by_hash(caller, contract_hash, entry_point, args)
+                .build();
+
+        builder.exec(call_request)
+    }
+
+    fn install_key_change_proposals(
+        builder: &mut InMemoryWasmTestBuilder,
+        signers: BTreeMap<AccountHash, u8>,
+        approval_threshold: u8,
+    ) -> ContractHash {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_CHANGE_PROPOSALS_WASM,
+            runtime_args! {
+                key_change::RUNTIME_ARG_SIGNERS => signers,
+                key_change::RUNTIME_ARG_APPROVAL_THRESHOLD => approval_threshold,
+            },
+        )
+        .build();
+
+        builder.exec(install_request).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        account
+            .named_keys()
+            .get(key_change::NAMED_KEY_CONTRACT_HASH)
+            .and_then(|key| key.into_hash())
+            .map(ContractHash::new)
+            .expect("should have the proposals contract hash")
+    }
+
+    fn apply_key_change(
+        builder: &mut InMemoryWasmTestBuilder,
+        proposal_id: u64,
+    ) -> &mut InMemoryWasmTestBuilder {
+        let apply_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            APPLY_KEY_CHANGE_WASM,
+            runtime_args! {
+                key_change::RUNTIME_ARG_PROPOSAL_ID => proposal_id,
+            },
+        )
+        .build();
+
+        builder.exec(apply_request)
+    }
+
+    /// Builds a deploy of the default account, authorized by `authorization_keys`.
+    fn multisig_request(
+        authorization_keys: &[AccountHash],
+        session_file: &str,
+        session_args: RuntimeArgs,
+        block_time: u64,
+    ) -> ExecuteRequest {
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT,
+            })
+            .with_authorization_keys(authorization_keys)
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(session_file, session_args)
+            .build();
+
+        ExecuteRequestBuilder::from_deploy_item(deploy_item)
+            .with_block_time(block_time)
+            .build()
+    }
+
+    /// Bonds `validator` as a validator, bidding from its own funded account.
+    fn add_validator_bid(builder: &mut InMemoryWasmTestBuilder, validator: &PublicKey) {
+        let auction_hash = builder.get_auction_contract_hash();
+        let add_bid_request = ExecuteRequestBuilder::contract_call_by_hash(
+            validator.to_account_hash(),
+            auction_hash,
+            auction::METHOD_ADD_BID,
+            runtime_args! {
+                auction::ARG_PUBLIC_KEY => validator.clone(),
+                auction::ARG_AMOUNT => U512::from(1_000_000_000_000u64),
+                auction::ARG_DELEGATION_RATE => 10u8,
+            },
+        )
+        .build();
+
+        builder.exec(add_bid_request).expect_success().commit();
+    }
+
+    fn query_proposal(
+        builder: &InMemoryWasmTestBuilder,
+        contract_hash: ContractHash,
+        proposal_id: u64,
+    ) -> Proposal {
+        let contract = builder
+            .get_contract(contract_hash)
+            .expect("should have the wallet contract");
+        let proposals = contract
+            .named_keys()
+            .get(DICTIONARY_PROPOSALS)
+            .and_then(Key::as_uref)
+            .expect("should have the proposals dictionary");
+        let item_key = proposal_id.to_string();
+
+        builder
+            .query(None, Key::dictionary(*proposals, item_key.as_bytes()), &[])
+            .expect("should have the proposal")
+            .as_cl_value()
+            .cloned()
+            .expect("should be a CLValue")
+            .into_t()
+            .expect("should be a proposal")
+    }
+
+    #[test]
+    fn should_describe_every_user_error_code() {
+        let mut codes_and_messages = Vec::new();
+        codes_and_messages.extend(
+            AddAccountError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            ConfigureMultisigError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            RemoveAccountError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(RotateKeyError::ALL.iter().map(|e| (*e as u16, e.message())));
+        codes_and_messages.extend(
+            UpdateAssociatedKeysError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            UpdateThresholdsError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            FreezeAccountError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            UnfreezeAccountError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            SweepExpiredKeysError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            MultisigWalletError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            KeyChangeProposalsError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            ApplyKeyChangeError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            QueueKeyChangeError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            ExecuteKeyChangeError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            CancelKeyChangeError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(TransferError::ALL.iter().map(|e| (*e as u16, e.message())));
+        codes_and_messages.extend(
+            SetSpendingLimitError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(DelegateError::ALL.iter().map(|e| (*e as u16, e.message())));
+        codes_and_messages.extend(
+            UndelegateError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            RedelegateError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            KeyManagerError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+        codes_and_messages.extend(
+            SnapshotAccountError::ALL
+                .iter()
+                .map(|e| (*e as u16, e.message())),
+        );
+
+        let mut codes = codes_and_messages
+            .iter()
+            .map(|(code, _)| *code)
+            .collect::<Vec<_>>();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), codes_and_messages.len());
+
+        for (code, message) in codes_and_messages {
+            assert_eq!(user_error_message(code), Some(message));
+        }
+
+        assert_eq!(user_error_message(0), None);
+        assert_eq!(
+            ApiError::from(RemoveAccountError::LockoutRisk),
+            ApiError::User(301)
+        );
+    }
+
     #[test]
     fn should_update_primary_key_weight() {
         let mut builder = InMemoryWasmTestBuilder::default();
@@ -60,13 +511,12 @@ mod tests {
     }
 
     #[test]
-    fn should_add_new_accounts_to_primary_associated_keys() {
+    fn should_revert_zero_weight_update() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Add User Account 1 to the Default Account Associated Keys
         let add_key_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             ADD_ACCOUNT_WASM,
@@ -79,38 +529,23 @@ mod tests {
 
         builder.exec(add_key_request).expect_success().commit();
 
-        // Prepare assertions.
-        let account = builder
-            .get_account(*DEFAULT_ACCOUNT_ADDR)
-            .expect("Should be an account.");
-        let actual_weight = account.associated_keys().get(&USER_1_ACCOUNT).unwrap();
-
-        assert_eq!(actual_weight, &Weight::new(1));
-
-        // Add User Account 2 to the Default Account Associated Keys
-        let add_key_request = ExecuteRequestBuilder::standard(
+        let update_weight_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
-            ADD_ACCOUNT_WASM,
+            UPDATE_KEYS_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(0),
             },
         )
         .build();
 
-        builder.exec(add_key_request).expect_success().commit();
-
-        // Prepare assertions.
-        let account = builder
-            .get_account(*DEFAULT_ACCOUNT_ADDR)
-            .expect("Should be an account.");
-        let actual_weight = account.associated_keys().get(&USER_2_ACCOUNT).unwrap();
+        builder.exec(update_weight_request).expect_failure();
 
-        assert_eq!(actual_weight, &Weight::new(1));
+        assert_reverted_with(&builder, UpdateAssociatedKeysError::ZeroWeight.into());
     }
 
     #[test]
-    fn should_update_primary_key_weight_and_thresholds() {
+    fn should_revert_weight_update_below_thresholds() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
@@ -131,22 +566,12 @@ mod tests {
             .expect_success()
             .commit();
 
-        // Prepare assertions.
-        let account = builder
-            .get_account(*DEFAULT_ACCOUNT_ADDR)
-            .expect("Should be an account.");
-        let actual_weight = account
-            .associated_keys()
-            .get(&DEFAULT_ACCOUNT_ADDR)
-            .unwrap();
-        assert_eq!(actual_weight, &Weight::new(3));
-
         let update_threshold_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             UPDATE_THRESHOLDS_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
-                RUNTIME_ARG_NEW_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
             },
         )
         .build();
@@ -156,64 +581,81 @@ mod tests {
             .expect_success()
             .commit();
 
-        // Prepare assertions.
-        let account = builder
-            .get_account(*DEFAULT_ACCOUNT_ADDR)
-            .expect("Should be an account.");
+        // The primary key is the only key, so weight 2 could never reach the key management threshold of 3
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
 
-        let key_mgmt_threshold = account.action_thresholds().key_management();
-        let deployment_threshold = account.action_thresholds().deployment();
+        builder.exec(update_weight_request).expect_failure();
 
-        assert_eq!(key_mgmt_threshold, &Weight::new(3));
-        assert_eq!(deployment_threshold, &Weight::new(2));
+        assert_reverted_with(&builder, UpdateAssociatedKeysError::LockoutRisk.into());
     }
 
     #[test]
-    fn should_add_two_keys_and_remove_one() {
+    fn should_revert_weight_update_to_the_recorded_weight() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Add User Account 1 to the Default Account Associated Keys
-        let add_key_request = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            ADD_ACCOUNT_WASM,
+        configure_default_account(&mut builder, 1, &[(USER_1_ACCOUNT, 2)], 1, 2);
+        let authorization_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
+        let audit_log_length = query_audit_log::<u64>(&builder, AUDIT_LOG_LENGTH);
+
+        let update_weight_request = multisig_request(
+            &authorization_keys,
+            UPDATE_KEYS_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
             },
-        )
-        .build();
+            0,
+        );
 
-        builder.exec(add_key_request).expect_success().commit();
+        builder.exec(update_weight_request).expect_failure();
 
-        // Add User Account 2 to the Default Account Associated Keys
-        let add_key_request = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            ADD_ACCOUNT_WASM,
-            runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
-            },
-        )
-        .build();
+        assert_reverted_with(&builder, UpdateAssociatedKeysError::NoChange.into());
+        assert_eq!(
+            query_audit_log::<u64>(&builder, AUDIT_LOG_LENGTH),
+            audit_log_length
+        );
+    }
 
-        builder.exec(add_key_request).expect_success().commit();
+    #[test]
+    fn should_restore_a_weight_changed_outside_the_contracts() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
 
-        // Remove User Account 1 to the Default Account Associated Keys
-        let contract_installation_request = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            REMOVE_ACCOUNT_WASM,
+        configure_default_account(&mut builder, 1, &[(USER_1_ACCOUNT, 2)], 1, 2);
+        let authorization_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
+
+        // The audit log still records a weight of 2
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .update_associated_key(USER_1_ACCOUNT, Weight::new(3))
+                .expect("should update the key");
+        });
+
+        let update_weight_request = multisig_request(
+            &authorization_keys,
+            UPDATE_KEYS_WASM,
             runtime_args! {
-                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT)
-                ,
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
             },
-        )
-        .build();
+            0,
+        );
 
         builder
-            .exec(contract_installation_request)
+            .exec(update_weight_request)
             .expect_success()
             .commit();
 
@@ -222,108 +664,116 @@ mod tests {
             .get_account(*DEFAULT_ACCOUNT_ADDR)
             .expect("Should be an account.");
 
-        let missing_account_weight = account.associated_keys().get(&USER_1_ACCOUNT);
-
-        assert_eq!(missing_account_weight, None);
-
-        let existing_account_weight = account.associated_keys().get(&USER_2_ACCOUNT).unwrap();
-
-        assert_eq!(existing_account_weight, &Weight::new(1));
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(1));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(2)
+        );
     }
 
     #[test]
-    fn should_store_hello_world() {
+    fn should_revert_a_no_op_check_that_would_lose_a_threshold() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // The test framework checks for compiled Wasm files in '<current working dir>/wasm'.  Paths
-        // relative to the current working dir (e.g. 'wasm/contract.wasm') can also be used, as can
-        // absolute paths.
-        let session_code = PathBuf::from(CONTRACT_WASM);
-        let session_args = runtime_args! {
-            RUNTIME_ARG_NAME => TEST_VALUE,
-        };
+        configure_default_account(&mut builder, 1, &[(USER_1_ACCOUNT, 2)], 1, 2);
+        let authorization_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
 
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT
-            })
-            .with_session_code(session_code, session_args)
-            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .build();
+        // The audit log still records a key management threshold of 2, so the host accepts a
+        // deployment threshold of 3 probing for a higher one
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .set_action_threshold(ActionType::KeyManagement, Weight::new(3))
+                .expect("should set the threshold");
+        });
 
-        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
-
-        // prepare assertions.
-        let result_of_query = builder.query(
-            None,
-            Key::Account(*DEFAULT_ACCOUNT_ADDR),
-            &[KEY_NAME.to_string()],
+        let update_weight_request = multisig_request(
+            &authorization_keys,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+            0,
         );
-        assert!(result_of_query.is_err());
 
-        // deploy the contract.
-        builder.exec(execute_request).commit().expect_success();
-
-        // make assertions
-        let result_of_query = builder
-            .query(
-                None,
-                Key::Account(*DEFAULT_ACCOUNT_ADDR),
-                &[KEY_NAME.to_string()],
-            )
-            .expect("should be stored value.")
-            .as_cl_value()
-            .expect("should be cl value.")
-            .clone()
-            .into_t::<String>()
-            .expect("should be string.");
+        builder.exec(update_weight_request).expect_failure();
 
-        assert_eq!(result_of_query, TEST_VALUE);
+        assert_reverted_with(
+            &builder,
+            UpdateAssociatedKeysError::UnrecordedThresholds.into(),
+        );
     }
 
     #[test]
-    fn should_check_thresholds_errors() {
+    fn should_revert_threshold_update_to_the_recorded_thresholds() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Step 3: Increase the primary key's weight to set thresholds
-        let update_weight_request = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
-            UPDATE_KEYS_WASM,
+        configure_default_account(&mut builder, 2, &[(USER_1_ACCOUNT, 2)], 2, 3);
+        let authorization_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
+        let audit_log_length = query_audit_log::<u64>(&builder, AUDIT_LOG_LENGTH);
+
+        // Setting either threshold alone to its recorded value changes nothing either
+        for threshold_args in [
             runtime_args! {
-                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
-                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
             },
-        )
-        .build();
-        builder
-            .exec(update_weight_request)
-            .expect_success()
-            .commit();
+            runtime_args! {
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        ] {
+            let update_threshold_request = multisig_request(
+                &authorization_keys,
+                UPDATE_THRESHOLDS_WASM,
+                threshold_args,
+                0,
+            );
 
-        // Step 4: Update the default account's action thresholds
-        let update_threshold_request = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
+            builder.exec(update_threshold_request).expect_failure();
+
+            assert_reverted_with(&builder, UpdateThresholdsError::NoChange.into());
+        }
+
+        assert_eq!(
+            query_audit_log::<u64>(&builder, AUDIT_LOG_LENGTH),
+            audit_log_length
+        );
+
+        // Changing one of the two thresholds is not a no-op
+        let update_threshold_request = multisig_request(
+            &authorization_keys,
             UPDATE_THRESHOLDS_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
-                RUNTIME_ARG_NEW_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
             },
-        )
-        .build();
+            0,
+        );
 
         builder
             .exec(update_threshold_request)
             .expect_success()
             .commit();
+    }
 
-        // Step 5: Add associated keys to the primary account
+    #[test]
+    fn should_add_new_accounts_to_primary_associated_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Add User Account 1 to the Default Account Associated Keys
         let add_key_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             ADD_ACCOUNT_WASM,
@@ -334,218 +784,3872 @@ mod tests {
         )
         .build();
 
-        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 2, equal key management threshold && deployment threshold
         builder.exec(add_key_request).expect_success().commit();
 
-        // Step 6: Send a multi-signature deploy from the primary account
-        let session_code = PathBuf::from(CONTRACT_WASM);
-        let session_args = runtime_args! {
-            RUNTIME_ARG_NAME => TEST_VALUE,
-        };
-
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT
-            })
-            .with_session_code(session_code, session_args)
-            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .build();
-
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item.clone()).build();
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let actual_weight = account.associated_keys().get(&USER_1_ACCOUNT).unwrap();
 
-        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 2, equal deployment threshold
-        builder.exec(deploy_request).expect_success().commit();
+        assert_eq!(actual_weight, &Weight::new(1));
 
-        // Let's test if a decreased weight for DEFAULT_ACCOUNT_ADDR blocks deployement
-        let update_weight_request = ExecuteRequestBuilder::standard(
+        // Add User Account 2 to the Default Account Associated Keys
+        let add_key_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
-            UPDATE_KEYS_WASM,
+            ADD_ACCOUNT_WASM,
             runtime_args! {
-                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
-                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
             },
         )
         .build();
 
-        builder
-            .exec(update_weight_request)
-            .commit()
-            .expect_success();
+        builder.exec(add_key_request).expect_success().commit();
 
-        // This deploy request should fail as DEFAULT_ACCOUNT_ADDR has weight 1, below deployment threshold of 2
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
-        builder.exec(deploy_request).expect_failure();
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let actual_weight = account.associated_keys().get(&USER_2_ACCOUNT).unwrap();
 
-        let actual_error = builder.get_error().expect("must have error");
-        assert!(
-            matches!(
-                actual_error,
-                EngineStateError::Exec(execution::Error::DeploymentAuthorizationFailure)
-            ),
-            "Expected {:?}, received {:?}",
-            EngineStateError::Exec(execution::Error::DeploymentAuthorizationFailure),
-            actual_error
+        assert_eq!(actual_weight, &Weight::new(1));
+    }
+
+    #[test]
+    fn should_manage_associated_keys_by_public_key_and_account_hash() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let ed25519_public_key = PublicKey::from(
+            &SecretKey::ed25519_from_bytes([4u8; 32]).expect("should create ed25519 key"),
+        );
+        let secp256k1_public_key = PublicKey::from(
+            &SecretKey::secp256k1_from_bytes([5u8; 32]).expect("should create secp256k1 key"),
         );
 
-        // Let's decrease deployement threshold to meet DEFAULT_ACCOUNT_ADDR weight
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT,
-            })
-            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .with_session_code(
-                UPDATE_THRESHOLDS_WASM,
+        for public_key in [&ed25519_public_key, &secp256k1_public_key] {
+            let add_key_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                ADD_ACCOUNT_WASM,
                 runtime_args! {
-                    RUNTIME_ARG_NEW_DEPLOYMENT_THRESHOLD =>  Weight::new(1),
-                    RUNTIME_ARG_NEW_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+                    RUNTIME_ARG_NEW_ASSOCIATED_PUBLIC_KEY => public_key.clone(),
+                    RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
                 },
             )
             .build();
 
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
-        builder.exec(deploy_request).expect_success().commit();
+            builder.exec(add_key_request).expect_success().commit();
+        }
 
-        // This request should fail as DEFAULT_ACCOUNT_ADDR has weight 1, still below key management threshold of 2
         let add_key_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             ADD_ACCOUNT_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
                 RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
             },
         )
         .build();
 
-        builder.exec(add_key_request).expect_failure();
+        builder.exec(add_key_request).expect_success().commit();
 
-        let actual_error = builder.get_error().expect("must have error");
-        assert!(
-            matches!(
-                actual_error,
-                EngineStateError::Exec(execution::Error::Revert(ApiError::PermissionDenied))
-            ),
-            "Expected {:?}, received {:?}",
-            EngineStateError::Exec(execution::Error::Revert(ApiError::PermissionDenied)),
-            actual_error
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_PUBLIC_KEY => ed25519_public_key.clone(),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account
+                .associated_keys()
+                .get(&AccountHash::from(&ed25519_public_key)),
+            None
+        );
+        assert_eq!(
+            account
+                .associated_keys()
+                .get(&AccountHash::from(&secp256k1_public_key)),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(2))
         );
     }
 
     #[test]
-    fn should_execute_tutorial_steps() {
+    fn should_revert_when_more_than_one_account_argument_is_supplied() {
         let mut builder = InMemoryWasmTestBuilder::default();
         builder
             .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Step 3: Increase the primary key's weight to set thresholds
         let update_weight_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             UPDATE_KEYS_WASM,
             runtime_args! {
                 RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
-                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
             },
         )
         .build();
+
+        builder.exec(update_weight_request).expect_failure();
+
+        assert_reverted_with(&builder, UpdateAssociatedKeysError::InvalidAccount.into());
+    }
+
+    #[test]
+    fn should_add_batch_of_accounts_to_primary_associated_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
         builder
-            .exec(update_weight_request)
-            .expect_success()
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Step 4: Update the default account's action thresholds
-        let update_threshold_request = ExecuteRequestBuilder::standard(
+        let mut new_associated_keys = BTreeMap::new();
+        new_associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        new_associated_keys.insert(Key::from(USER_2_ACCOUNT), 2u8);
+
+        let add_keys_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
-            UPDATE_THRESHOLDS_WASM,
+            ADD_ACCOUNT_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
-                RUNTIME_ARG_NEW_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEYS => new_associated_keys,
             },
         )
         .build();
 
+        builder.exec(add_keys_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(
+            account.associated_keys().get(&USER_2_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+    }
+
+    #[test]
+    fn should_revert_whole_batch_when_one_add_fails() {
+        let mut builder = InMemoryWasmTestBuilder::default();
         builder
-            .exec(update_threshold_request)
-            .expect_success()
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
             .commit();
 
-        // Step 5: Add associated keys to the primary account
-        let add_key_request = ExecuteRequestBuilder::standard(
+        // The primary key is already associated, so adding it again fails with a duplicate key
+        let mut new_associated_keys = BTreeMap::new();
+        new_associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        new_associated_keys.insert(Key::from(*DEFAULT_ACCOUNT_ADDR), 1u8);
+
+        let add_keys_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             ADD_ACCOUNT_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEYS => new_associated_keys,
             },
         )
         .build();
 
-        builder.exec(add_key_request).expect_success().commit();
+        builder.exec(add_keys_request).expect_failure();
 
-        let add_key_request = ExecuteRequestBuilder::standard(
+        assert_reverted_with(&builder, AddAccountError::DuplicateKey.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let missing_account_weight = account.associated_keys().get(&USER_1_ACCOUNT);
+
+        assert_eq!(missing_account_weight, None);
+    }
+
+    #[test]
+    fn should_revert_empty_batch() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_keys_request = ExecuteRequestBuilder::standard(
             *DEFAULT_ACCOUNT_ADDR,
             ADD_ACCOUNT_WASM,
             runtime_args! {
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
-                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEYS => BTreeMap::<Key, u8>::new(),
             },
         )
         .build();
 
-        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 3, above or equal key management threshold && deployment threshold
-        builder.exec(add_key_request).expect_success().commit();
+        builder.exec(add_keys_request).expect_failure();
 
-        // Step 6: Send a deploy from the primary account
-        let session_code = PathBuf::from(CONTRACT_WASM);
-        let session_args = runtime_args! {
+        assert_reverted_with(&builder, AddAccountError::EmptyBatch.into());
+    }
+
+    #[test]
+    fn should_update_primary_key_weight_and_thresholds() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let actual_weight = account
+            .associated_keys()
+            .get(&DEFAULT_ACCOUNT_ADDR)
+            .unwrap();
+        assert_eq!(actual_weight, &Weight::new(3));
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        let key_mgmt_threshold = account.action_thresholds().key_management();
+        let deployment_threshold = account.action_thresholds().deployment();
+
+        assert_eq!(key_mgmt_threshold, &Weight::new(3));
+        assert_eq!(deployment_threshold, &Weight::new(2));
+    }
+
+    #[test]
+    fn should_raise_and_lower_thresholds_in_any_order() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        // Each pair is (deployment, key management), starting from the genesis thresholds (1, 1)
+        let transitions = [
+            // Raise both
+            (2, 3),
+            // Lower key management below the current deployment threshold
+            (1, 1),
+            // Raise deployment above the current key management threshold
+            (3, 3),
+            // Lower both
+            (1, 2),
+            (2, 2),
+        ];
+
+        for (deployment, key_management) in transitions {
+            let update_threshold_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                UPDATE_THRESHOLDS_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(deployment),
+                    RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(key_management),
+                },
+            )
+            .build();
+
+            builder
+                .exec(update_threshold_request)
+                .expect_success()
+                .commit();
+
+            // Prepare assertions.
+            let account = builder
+                .get_account(*DEFAULT_ACCOUNT_ADDR)
+                .expect("Should be an account.");
+
+            assert_eq!(
+                account.action_thresholds().deployment(),
+                &Weight::new(deployment)
+            );
+            assert_eq!(
+                account.action_thresholds().key_management(),
+                &Weight::new(key_management)
+            );
+        }
+    }
+
+    #[test]
+    fn should_update_a_single_threshold() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(1));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+    }
+
+    #[test]
+    fn should_revert_threshold_update_without_thresholds() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(update_threshold_request).expect_failure();
+
+        assert_reverted_with(&builder, UpdateThresholdsError::MissingThresholds.into());
+    }
+
+    #[test]
+    fn should_revert_deployment_threshold_above_key_management() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(update_threshold_request).expect_failure();
+
+        assert_reverted_with(&builder, UpdateThresholdsError::InvalidThresholds.into());
+    }
+
+    #[test]
+    fn should_add_two_keys_and_remove_one() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Add User Account 1 to the Default Account Associated Keys
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        // Add User Account 2 to the Default Account Associated Keys
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        // Remove User Account 1 to the Default Account Associated Keys
+        let contract_installation_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT)
+                ,
+            },
+        )
+        .build();
+
+        builder
+            .exec(contract_installation_request)
+            .expect_success()
+            .commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        let missing_account_weight = account.associated_keys().get(&USER_1_ACCOUNT);
+
+        assert_eq!(missing_account_weight, None);
+
+        let existing_account_weight = account.associated_keys().get(&USER_2_ACCOUNT).unwrap();
+
+        assert_eq!(existing_account_weight, &Weight::new(1));
+    }
+
+    #[test]
+    fn should_not_remove_key_that_would_lock_the_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        // Removing the primary key would leave USER_1_ACCOUNT alone with weight 1, below both thresholds
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::LockoutRisk.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let actual_weight = account
+            .associated_keys()
+            .get(&DEFAULT_ACCOUNT_ADDR)
+            .unwrap();
+
+        assert_eq!(actual_weight, &Weight::new(3));
+    }
+
+    #[test]
+    fn should_revert_when_removing_a_missing_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::MissingKey.into());
+    }
+
+    #[test]
+    fn should_revert_when_removing_a_non_account_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::Hash([1u8; 32]),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::InvalidAccount.into());
+    }
+
+    #[test]
+    fn should_revert_when_removing_a_key_without_key_management_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        for user_account in [USER_1_ACCOUNT, USER_2_ACCOUNT] {
+            let add_key_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                ADD_ACCOUNT_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(user_account),
+                    RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                },
+            )
+            .build();
+
+            builder.exec(add_key_request).expect_success().commit();
+        }
+
+        // USER_1_ACCOUNT meets the deployment threshold of 1 but not the key management threshold of 3
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT,
+            })
+            .with_authorization_keys(&[USER_1_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(
+                REMOVE_ACCOUNT_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                },
+            )
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        builder.exec(deploy_request).expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::PermissionDenied.into());
+    }
+
+    #[test]
+    fn should_rotate_associated_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.associated_keys().get(&USER_1_ACCOUNT), None);
+        assert_eq!(
+            account.associated_keys().get(&USER_3_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+    }
+
+    #[test]
+    fn should_keep_the_old_key_weight_on_rotation() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        // The audit log records a weight of 2 for the old key
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RotateKeyError::WeightMismatch.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+        assert_eq!(account.associated_keys().get(&USER_3_ACCOUNT), None);
+    }
+
+    #[test]
+    fn should_rotate_a_key_unknown_to_the_audit_log_to_the_given_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .add_associated_key(USER_1_ACCOUNT, Weight::new(2))
+                .expect("should add the key");
+        });
+
+        let rotate_args = |weight: Option<u8>| {
+            let mut args = runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+            };
+            if let Some(weight) = weight {
+                args.insert(RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT, weight)
+                    .expect("should insert the weight");
+            }
+            args
+        };
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            rotate_args(None),
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RotateKeyError::UnknownWeight.into());
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            rotate_args(Some(2)),
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.associated_keys().get(&USER_1_ACCOUNT), None);
+        assert_eq!(
+            account.associated_keys().get(&USER_3_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+    }
+
+    #[test]
+    fn should_revert_rotation_to_zero_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(0),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RotateKeyError::ZeroWeight.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(account.associated_keys().get(&USER_3_ACCOUNT), None);
+    }
+
+    #[test]
+    fn should_revert_rotation_of_a_missing_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RotateKeyError::MissingKey.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.associated_keys().get(&USER_3_ACCOUNT), None);
+    }
+
+    #[test]
+    fn should_revert_rotation_to_an_existing_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        for user_account in [USER_1_ACCOUNT, USER_3_ACCOUNT] {
+            let add_key_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                ADD_ACCOUNT_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(user_account),
+                    RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                },
+            )
+            .build();
+
+            builder.exec(add_key_request).expect_success().commit();
+        }
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_failure();
+
+        assert_reverted_with(&builder, RotateKeyError::DuplicateKey.into());
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+    }
+
+    #[test]
+    fn should_store_hello_world() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // The test framework checks for compiled Wasm files in '<current working dir>/wasm'.  Paths
+        // relative to the current working dir (e.g. 'wasm/contract.wasm') can also be used, as can
+        // absolute paths.
+        let session_code = PathBuf::from(CONTRACT_WASM);
+        let session_args = runtime_args! {
+            RUNTIME_ARG_NAME => TEST_VALUE,
+        };
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code, session_args)
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        // prepare assertions.
+        let result_of_query = builder.query(
+            None,
+            Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            &[KEY_NAME.to_string()],
+        );
+        assert!(result_of_query.is_err());
+
+        // deploy the contract.
+        builder.exec(execute_request).commit().expect_success();
+
+        // make assertions
+        let result_of_query = builder
+            .query(
+                None,
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                &[KEY_NAME.to_string()],
+            )
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<String>()
+            .expect("should be string.");
+
+        assert_eq!(result_of_query, TEST_VALUE);
+    }
+
+    #[test]
+    fn should_check_thresholds_errors() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Step 3: Increase the primary key's weight to set thresholds
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        // Step 4: Update the default account's action thresholds
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Step 5: Add associated keys to the primary account
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 2, equal key management threshold && deployment threshold
+        builder.exec(add_key_request).expect_success().commit();
+
+        // Step 6: Send a multi-signature deploy from the primary account
+        let session_code = PathBuf::from(CONTRACT_WASM);
+        let session_args = runtime_args! {
+            RUNTIME_ARG_NAME => TEST_VALUE,
+        };
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code, session_args)
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item.clone()).build();
+
+        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 2, equal deployment threshold
+        builder.exec(deploy_request).expect_success().commit();
+
+        // Let's test if a decreased weight for DEFAULT_ACCOUNT_ADDR blocks deployement
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .commit()
+            .expect_success();
+
+        // This deploy request should fail as DEFAULT_ACCOUNT_ADDR has weight 1, below deployment threshold of 2
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        builder.exec(deploy_request).expect_failure();
+
+        let actual_error = builder.get_error().expect("must have error");
+        assert!(
+            matches!(
+                actual_error,
+                EngineStateError::Exec(execution::Error::DeploymentAuthorizationFailure)
+            ),
+            "Expected {:?}, received {:?}",
+            EngineStateError::Exec(execution::Error::DeploymentAuthorizationFailure),
+            actual_error
+        );
+
+        // Let's decrease deployement threshold to meet DEFAULT_ACCOUNT_ADDR weight
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT,
+            })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(
+                UPDATE_THRESHOLDS_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DEPLOYMENT_THRESHOLD =>  Weight::new(1),
+                    RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+                },
+            )
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        builder.exec(deploy_request).expect_success().commit();
+
+        // This request should fail as DEFAULT_ACCOUNT_ADDR has weight 1, still below key management threshold of 2
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_failure();
+
+        assert_reverted_with(&builder, AddAccountError::PermissionDenied.into());
+    }
+
+    #[test]
+    fn should_execute_tutorial_steps() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Step 3: Increase the primary key's weight to set thresholds
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(*DEFAULT_ACCOUNT_ADDR),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        // Step 4: Update the default account's action thresholds
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD =>  Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Step 5: Add associated keys to the primary account
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 3, above or equal key management threshold && deployment threshold
+        builder.exec(add_key_request).expect_success().commit();
+
+        // Step 6: Send a deploy from the primary account
+        let session_code = PathBuf::from(CONTRACT_WASM);
+        let session_args = runtime_args! {
+            RUNTIME_ARG_NAME => TEST_VALUE,
+        };
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code.clone(), session_args.clone())
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 3, greater than the deployment threshold
+        builder.exec(deploy_request).expect_success().commit();
+
+        // Step 7: Send a multi-signature deploy from an associated key
+
+        // This deploy request should fail as USER_1_ACCOUNT has weight 1, below deployment threshold of 2
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code.clone(), session_args.clone())
+            .with_authorization_keys(&[USER_1_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_failure();
+
+        // This deploy request should succeed as USER_1_ACCOUNT + USER_2_ACCOUNT have weight 2, equal deployment threshold of 2
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code, session_args)
+            .with_authorization_keys(&[USER_1_ACCOUNT, USER_2_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_execute_tutorial_steps_in_one_deploy() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Steps 3 to 5: Configure the primary key weight, thresholds and associated keys at once
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(
+            account.associated_keys().get(&DEFAULT_ACCOUNT_ADDR),
+            Some(&Weight::new(3))
+        );
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(
+            account.associated_keys().get(&USER_2_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+
+        // Step 6: Send a deploy from the primary account
+        let session_code = PathBuf::from(CONTRACT_WASM);
+        let session_args = runtime_args! {
+            RUNTIME_ARG_NAME => TEST_VALUE,
+        };
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code.clone(), session_args.clone())
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_success().commit();
+
+        // Step 7: Send a multi-signature deploy from an associated key
+
+        // This deploy request should fail as USER_1_ACCOUNT has weight 1, below deployment threshold of 2
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code.clone(), session_args.clone())
+            .with_authorization_keys(&[USER_1_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_failure();
+
+        // This deploy request should succeed as USER_1_ACCOUNT + USER_2_ACCOUNT have weight 2, equal deployment threshold of 2
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code, session_args)
+            .with_authorization_keys(&[USER_1_ACCOUNT, USER_2_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_revert_configuration_with_deployment_above_key_management() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => BTreeMap::<Key, u8>::new(),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(3),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_failure();
+
+        assert_reverted_with(&builder, ConfigureMultisigError::InvalidThresholds.into());
+    }
+
+    #[test]
+    fn should_revert_configuration_with_unreachable_thresholds() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // Total weight of 2 can never reach the key management threshold of 3
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_failure();
+
+        assert_reverted_with(
+            &builder,
+            ConfigureMultisigError::UnreachableThresholds.into(),
+        );
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.associated_keys().get(&USER_1_ACCOUNT), None);
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(1)
+        );
+    }
+
+    #[test]
+    fn should_revert_configuration_leaving_unlisted_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        // USER_3_ACCOUNT is not listed, so the account would keep a key the caller did not declare
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys.clone(),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_failure();
+
+        assert_reverted_with(&builder, ConfigureMultisigError::UnlistedKeys.into());
+
+        // Listing the key makes the declared configuration match the account
+        associated_keys.insert(Key::from(USER_3_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.associated_keys().len(), 4);
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+    }
+
+    #[test]
+    fn should_revert_configuration_without_key_management_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys.clone(),
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        // USER_1_ACCOUNT + USER_2_ACCOUNT meet the deployment threshold but not key management
+        let configure_request = multisig_request(
+            &[USER_1_ACCOUNT, USER_2_ACCOUNT],
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(1),
+            },
+            0,
+        );
+
+        builder.exec(configure_request).expect_failure();
+
+        assert_reverted_with(&builder, ConfigureMultisigError::PermissionDenied.into());
+    }
+
+    #[test]
+    fn should_freeze_and_unfreeze_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        let freeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            FREEZE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(freeze_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(3));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+
+        let frozen_thresholds = builder
+            .query(
+                None,
+                Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                &[NAMED_KEY_FROZEN_THRESHOLDS.to_string()],
+            )
+            .expect("should have frozen thresholds")
+            .as_cl_value()
+            .cloned()
+            .expect("should be a CLValue")
+            .into_t::<(u8, u8)>()
+            .expect("should be a pair of thresholds");
+        assert_eq!(frozen_thresholds, (2, 3));
+
+        // USER_1_ACCOUNT + USER_2_ACCOUNT no longer meet the raised deployment threshold
+        let session_code = PathBuf::from(CONTRACT_WASM);
+        let session_args = runtime_args! {
             RUNTIME_ARG_NAME => TEST_VALUE,
         };
 
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT
-            })
-            .with_session_code(session_code.clone(), session_args.clone())
-            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .build();
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code.clone(), session_args.clone())
+            .with_authorization_keys(&[USER_1_ACCOUNT, USER_2_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_failure();
+
+        let unfreeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UNFREEZE_ACCOUNT_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(unfreeze_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+        assert!(!account
+            .named_keys()
+            .contains_key(NAMED_KEY_FROZEN_THRESHOLDS));
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(session_code, session_args)
+            .with_authorization_keys(&[USER_1_ACCOUNT, USER_2_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+
+        builder.exec(deploy_request).expect_success().commit();
+    }
+
+    #[test]
+    fn should_revert_freezing_a_frozen_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        for _ in 0..2 {
+            let freeze_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                FREEZE_ACCOUNT_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                    RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(1),
+                },
+            )
+            .build();
+
+            builder.exec(freeze_request).commit();
+        }
+
+        assert_reverted_with(&builder, FreezeAccountError::AlreadyFrozen.into());
+    }
+
+    #[test]
+    fn should_revert_freezing_with_thresholds_that_do_not_match() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        // The account has a deployment threshold of 2 and a key management threshold of 3
+        for (deployment_threshold, key_mgmt_threshold) in [(1u8, 3u8), (3, 3), (2, 2), (2, 6)] {
+            let freeze_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                FREEZE_ACCOUNT_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(deployment_threshold),
+                    RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(key_mgmt_threshold),
+                },
+            )
+            .build();
+
+            builder.exec(freeze_request).expect_failure();
+
+            assert_reverted_with(&builder, FreezeAccountError::ThresholdMismatch.into());
+        }
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert!(account
+            .named_keys()
+            .get(NAMED_KEY_FROZEN_THRESHOLDS)
+            .is_none());
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+    }
+
+    #[test]
+    fn should_keep_a_higher_key_management_threshold_supplied_to_freeze() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        configure_default_account(
+            &mut builder,
+            3,
+            &[(USER_1_ACCOUNT, 1), (USER_2_ACCOUNT, 1)],
+            2,
+            3,
+        );
+
+        // The key management threshold is 3, but 4 is within the total weight of 5, so the host
+        // accepts it and the check cannot tell the difference
+        let freeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            FREEZE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(4),
+            },
+        )
+        .build();
+
+        builder.exec(freeze_request).expect_success().commit();
+
+        let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(4));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(4)
+        );
+
+        let unfreeze_request = multisig_request(
+            &[*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT],
+            UNFREEZE_ACCOUNT_WASM,
+            runtime_args! {},
+            0,
+        );
+
+        builder.exec(unfreeze_request).expect_success().commit();
+
+        // The deployment threshold is restored, but the account keeps the supplied key management
+        // threshold, as the README warns
+        let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(4)
+        );
+    }
+
+    #[test]
+    fn should_revert_unfreezing_an_account_that_is_not_frozen() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let unfreeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UNFREEZE_ACCOUNT_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(unfreeze_request).expect_failure();
+
+        assert_reverted_with(&builder, UnfreezeAccountError::NotFrozen.into());
+    }
+
+    #[test]
+    fn should_record_key_management_changes_in_audit_log() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .with_block_time(2_000)
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(1),
+            },
+        )
+        .with_block_time(3_000)
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+            },
+        )
+        .with_block_time(4_000)
+        .build();
+
+        builder.exec(remove_key_request).expect_success().commit();
+
+        // Prepare assertions.
+        let length: u64 = query_audit_log(&builder, AUDIT_LOG_LENGTH);
+        assert_eq!(length, 4);
+
+        let records = (0..length)
+            .map(|index| query_audit_log::<AuditRecord>(&builder, &index.to_string()))
+            .collect::<Vec<_>>();
+
+        let key_record = |operation, old, new, block_time| AuditRecord {
+            operation,
+            account_hash: Some(USER_1_ACCOUNT),
+            weight: ValueChange { old, new },
+            deployment_threshold: ValueChange::default(),
+            key_management_threshold: ValueChange::default(),
+            block_time,
+        };
+
+        assert_eq!(
+            records,
+            vec![
+                key_record(Operation::AddKey, None, Some(1), 1_000),
+                key_record(Operation::UpdateKeyWeight, Some(1), Some(2), 2_000),
+                AuditRecord {
+                    operation: Operation::UpdateThresholds,
+                    account_hash: None,
+                    weight: ValueChange::default(),
+                    deployment_threshold: ValueChange::default(),
+                    key_management_threshold: ValueChange {
+                        old: None,
+                        new: Some(1),
+                    },
+                    block_time: 3_000,
+                },
+                key_record(Operation::RemoveKey, Some(2), None, 4_000),
+            ]
+        );
+
+        // Clients without the crate read a record as nested tuples of its fields
+        type ValueChangeTuple = (Option<u8>, Option<u8>);
+        type RecordTuple = (
+            (u8, Option<AccountHash>, ValueChangeTuple),
+            (ValueChangeTuple, ValueChangeTuple),
+            u64,
+        );
+        let first_record: RecordTuple = query_audit_log(&builder, "0");
+        assert_eq!(
+            first_record,
+            (
+                (0, Some(USER_1_ACCOUNT), (None, Some(1))),
+                ((None, None), (None, None)),
+                1_000
+            )
+        );
+    }
+
+    #[test]
+    fn should_record_configuration_rotation_and_freeze_in_audit_log() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .with_block_time(2_000)
+        .build();
+
+        builder.exec(rotate_key_request).expect_success().commit();
+
+        let freeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            FREEZE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .with_block_time(3_000)
+        .build();
+
+        builder.exec(freeze_request).expect_success().commit();
+
+        let unfreeze_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UNFREEZE_ACCOUNT_WASM,
+            runtime_args! {},
+        )
+        .with_block_time(4_000)
+        .build();
+
+        builder.exec(unfreeze_request).expect_success().commit();
+
+        // Prepare assertions.
+        let length: u64 = query_audit_log(&builder, AUDIT_LOG_LENGTH);
+        assert_eq!(length, 7);
+
+        let records = (0..length)
+            .map(|index| query_audit_log::<AuditRecord>(&builder, &index.to_string()))
+            .collect::<Vec<_>>();
+
+        let key_record = |operation, account_hash, old, new, block_time| AuditRecord {
+            operation,
+            account_hash: Some(account_hash),
+            weight: ValueChange { old, new },
+            deployment_threshold: ValueChange::default(),
+            key_management_threshold: ValueChange::default(),
+            block_time,
+        };
+        let threshold_record =
+            |deployment_threshold, key_management_threshold, block_time| AuditRecord {
+                operation: Operation::UpdateThresholds,
+                account_hash: None,
+                weight: ValueChange::default(),
+                deployment_threshold,
+                key_management_threshold,
+                block_time,
+            };
+
+        assert_eq!(
+            records,
+            vec![
+                key_record(Operation::AddKey, USER_1_ACCOUNT, None, Some(1), 1_000),
+                key_record(
+                    Operation::UpdateKeyWeight,
+                    *DEFAULT_ACCOUNT_ADDR,
+                    None,
+                    Some(3),
+                    1_000
+                ),
+                threshold_record(
+                    ValueChange {
+                        old: None,
+                        new: Some(2)
+                    },
+                    ValueChange {
+                        old: None,
+                        new: Some(3)
+                    },
+                    1_000
+                ),
+                key_record(Operation::AddKey, USER_3_ACCOUNT, None, Some(1), 2_000),
+                key_record(Operation::RemoveKey, USER_1_ACCOUNT, Some(1), None, 2_000),
+                threshold_record(
+                    ValueChange {
+                        old: Some(2),
+                        new: Some(3)
+                    },
+                    ValueChange {
+                        old: Some(3),
+                        new: Some(3)
+                    },
+                    3_000
+                ),
+                threshold_record(
+                    ValueChange {
+                        old: Some(3),
+                        new: Some(2)
+                    },
+                    ValueChange {
+                        old: Some(3),
+                        new: Some(3)
+                    },
+                    4_000
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_record_failed_changes_in_audit_log() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_failure().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert!(!account.named_keys().contains_key(DICTIONARY_AUDIT_LOG));
+    }
+
+    fn query_account_snapshot(
+        builder: &InMemoryWasmTestBuilder,
+    ) -> (BTreeMap<AccountHash, u8>, u8, u8) {
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        let snapshot = *account
+            .named_keys()
+            .get(NAMED_KEY_ACCOUNT_SNAPSHOT)
+            .expect("should have a snapshot");
+
+        builder
+            .query(None, snapshot, &[])
+            .expect("should have the snapshot")
+            .as_cl_value()
+            .cloned()
+            .expect("should be a CLValue")
+            .into_t()
+            .expect("should have the expected type")
+    }
+
+    #[test]
+    fn should_snapshot_the_account_configuration() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let user_4_account = AccountHash::new([4u8; 32]);
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(user_4_account),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(3),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(4),
+            },
+        )
+        .build();
+        let snapshot_request = || {
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                SNAPSHOT_ACCOUNT_WASM,
+                runtime_args! {},
+            )
+            .build()
+        };
+        let account_configuration = |builder: &InMemoryWasmTestBuilder| {
+            let account = builder
+                .get_account(*DEFAULT_ACCOUNT_ADDR)
+                .expect("Should be an account.");
+            let keys = account
+                .associated_keys()
+                .iter()
+                .map(|(account_hash, weight)| (*account_hash, weight.value()))
+                .collect::<BTreeMap<_, _>>();
+            (
+                keys,
+                account.action_thresholds().deployment().value(),
+                account.action_thresholds().key_management().value(),
+            )
+        };
+
+        builder.exec(configure_request).expect_success().commit();
+        builder.exec(snapshot_request()).expect_success().commit();
+
+        assert_eq!(
+            query_account_snapshot(&builder),
+            account_configuration(&builder)
+        );
+
+        // The primary key alone no longer meets key management, which the snapshot checks use.
+        let key_management_snapshot_request = multisig_request(
+            &[*DEFAULT_ACCOUNT_ADDR, USER_2_ACCOUNT],
+            SNAPSHOT_ACCOUNT_WASM,
+            runtime_args! {},
+            0,
+        );
+        for request in [
+            add_key_request,
+            rotate_key_request,
+            update_threshold_request,
+            key_management_snapshot_request,
+        ] {
+            builder.exec(request).expect_success().commit();
+        }
+
+        let snapshot = query_account_snapshot(&builder);
+        assert_eq!(snapshot, account_configuration(&builder));
+        assert_eq!(snapshot.0.len(), 4);
+        assert!(!snapshot.0.contains_key(&USER_1_ACCOUNT));
+        assert_eq!((snapshot.1, snapshot.2), (3, 4));
+    }
+
+    #[test]
+    fn should_not_snapshot_a_configuration_changed_outside_the_contracts() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        configure_default_account(&mut builder, 3, &[(USER_1_ACCOUNT, 1)], 2, 3);
+
+        let snapshot_request = || {
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                SNAPSHOT_ACCOUNT_WASM,
+                runtime_args! {},
+            )
+            .build()
+        };
+        let account_configuration = |builder: &InMemoryWasmTestBuilder| {
+            let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+            let keys = account
+                .associated_keys()
+                .iter()
+                .map(|(account_hash, weight)| (*account_hash, weight.value()))
+                .collect::<BTreeMap<_, _>>();
+            (
+                keys,
+                account.action_thresholds().deployment().value(),
+                account.action_thresholds().key_management().value(),
+            )
+        };
+
+        builder.exec(snapshot_request()).expect_success().commit();
+        let snapshot = query_account_snapshot(&builder);
+        assert_eq!(snapshot, account_configuration(&builder));
+
+        // A key added by other session code is missing from the audit log
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .add_associated_key(USER_2_ACCOUNT, Weight::new(1))
+                .expect("should add key");
+        });
+
+        builder.exec(snapshot_request()).expect_failure();
+        assert_reverted_with(&builder, SnapshotAccountError::Mismatch.into());
+        assert_eq!(query_account_snapshot(&builder), snapshot);
+        assert_ne!(snapshot, account_configuration(&builder));
+
+        // So is a deployment threshold set by other session code
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .remove_associated_key(USER_2_ACCOUNT)
+                .expect("should remove key");
+            account
+                .set_action_threshold(ActionType::Deployment, Weight::new(1))
+                .expect("should set threshold");
+        });
+
+        builder.exec(snapshot_request()).expect_failure();
+        assert_reverted_with(&builder, SnapshotAccountError::Mismatch.into());
+        assert_ne!(snapshot, account_configuration(&builder));
+
+        // Once the account matches the audit log again, the snapshot is written
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .set_action_threshold(ActionType::Deployment, Weight::new(2))
+                .expect("should set threshold");
+        });
+
+        builder.exec(snapshot_request()).expect_success().commit();
+        assert_eq!(query_account_snapshot(&builder), snapshot);
+        assert_eq!(snapshot, account_configuration(&builder));
+    }
+
+    #[test]
+    fn should_not_snapshot_an_unconfigured_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // The audit log knows the added key, but not whether it is the only other key
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let snapshot_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            SNAPSHOT_ACCOUNT_WASM,
+            runtime_args! {},
+        )
+        .build();
+
+        builder.exec(snapshot_request).expect_failure();
+
+        assert_reverted_with(&builder, SnapshotAccountError::Unconfigured.into());
+    }
+
+    #[test]
+    fn should_keep_labels_of_associated_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL => "ops laptop",
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let mut expected_labels = BTreeMap::new();
+        expected_labels.insert(*DEFAULT_ACCOUNT_ADDR, None);
+        expected_labels.insert(USER_1_ACCOUNT, Some("ops laptop".to_string()));
+        expected_labels.insert(USER_2_ACCOUNT, None);
+        assert_eq!(list_key_labels(&builder), expected_labels);
+
+        // The replacement key takes over the label
+        let rotate_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ROTATE_KEY_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OLD_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_REPLACEMENT_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(rotate_key_request).expect_success().commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_KEYS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        expected_labels.remove(&USER_1_ACCOUNT);
+        expected_labels.insert(USER_3_ACCOUNT, Some("ops laptop".to_string()));
+        assert_eq!(list_key_labels(&builder), expected_labels);
+
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            REMOVE_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_success().commit();
+
+        expected_labels.remove(&USER_3_ACCOUNT);
+        assert_eq!(list_key_labels(&builder), expected_labels);
+
+        // A key added again later does not inherit the deleted label
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_3_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        expected_labels.insert(USER_3_ACCOUNT, None);
+        assert_eq!(list_key_labels(&builder), expected_labels);
+    }
+
+    #[test]
+    fn should_revert_empty_label() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL => "",
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_failure();
+
+        assert_reverted_with(&builder, AddAccountError::InvalidLabel.into());
+    }
+
+    #[test]
+    fn should_sweep_expired_keys() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        for (account, expires_at) in [
+            (USER_1_ACCOUNT, Some(5_000u64)),
+            (USER_2_ACCOUNT, Some(10_000)),
+            (USER_3_ACCOUNT, None),
+        ] {
+            let mut args = runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(account),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            };
+            if let Some(expires_at) = expires_at {
+                args.insert(RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY, expires_at)
+                    .unwrap();
+            }
+
+            let add_key_request =
+                ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, ADD_ACCOUNT_WASM, args)
+                    .with_block_time(1_000)
+                    .build();
+
+            builder.exec(add_key_request).expect_success().commit();
+        }
+
+        let sweep_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            SWEEP_EXPIRED_KEYS_WASM,
+            runtime_args! {},
+        )
+        .with_block_time(6_000)
+        .build();
+
+        builder.exec(sweep_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert!(!account.associated_keys().contains_key(&USER_1_ACCOUNT));
+        assert!(account.associated_keys().contains_key(&USER_2_ACCOUNT));
+        assert!(account.associated_keys().contains_key(&USER_3_ACCOUNT));
+
+        let sweep_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            SWEEP_EXPIRED_KEYS_WASM,
+            runtime_args! {},
+        )
+        .with_block_time(10_000)
+        .build();
+
+        builder.exec(sweep_request).expect_success().commit();
+
+        // Prepare assertions.
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+
+        assert!(!account.associated_keys().contains_key(&USER_2_ACCOUNT));
+        assert!(account.associated_keys().contains_key(&USER_3_ACCOUNT));
+    }
+
+    #[test]
+    fn should_not_sweep_expired_keys_that_would_lock_the_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(2),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY => 5_000u64,
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // The primary key alone (weight 1) could no longer reach the key management threshold
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(PathBuf::from(SWEEP_EXPIRED_KEYS_WASM), runtime_args! {})
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT])
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .build();
+
+        let sweep_request = ExecuteRequestBuilder::from_deploy_item(deploy_item)
+            .with_block_time(6_000)
+            .build();
+
+        builder.exec(sweep_request).expect_failure();
+
+        assert_reverted_with(&builder, SweepExpiredKeysError::LockoutRisk.into());
+    }
+
+    #[test]
+    fn should_revert_expiry_in_the_past() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_EXPIRY => 1_000u64,
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(add_key_request).expect_failure();
+
+        assert_reverted_with(&builder, AddAccountError::InvalidExpiry.into());
+    }
+
+    #[test]
+    fn should_transfer_from_multisig_wallet_after_approvals() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        fund_account(&mut builder, USER_1_ACCOUNT);
+        fund_account(&mut builder, USER_2_ACCOUNT);
+
+        let contract_hash = install_multisig_wallet(
+            &mut builder,
+            vec![*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT, USER_2_ACCOUNT],
+            2,
+        );
+
+        // Fund the wallet purse through the deposit purse of the installing account
+        let deposit_purse = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.")
+            .named_keys()
+            .get(NAMED_KEY_DEPOSIT_PURSE)
+            .and_then(Key::as_uref)
+            .copied()
+            .expect("should have the deposit purse");
+
+        let deposit_request = ExecuteRequestBuilder::transfer(
+            *DEFAULT_ACCOUNT_ADDR,
+            runtime_args! {
+                mint::ARG_AMOUNT => U512::from(10_000_000_000u64),
+                mint::ARG_TARGET => deposit_purse,
+                mint::ARG_ID => Option::<u64>::None,
+            },
+        )
+        .build();
+
+        builder.exec(deposit_request).expect_success().commit();
+
+        let amount = U512::from(4_000_000_000u64);
+        call_stored_contract(
+            &mut builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            ENTRY_POINT_PROPOSE_TRANSFER,
+            runtime_args! {
+                RUNTIME_ARG_TARGET => USER_3_ACCOUNT,
+                RUNTIME_ARG_AMOUNT => amount,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        // The proposer's approval alone does not meet the threshold of 2
+        call_stored_contract(
+            &mut builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            ENTRY_POINT_EXECUTE,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_failure();
+
+        assert_reverted_with(&builder, MultisigWalletError::InsufficientApprovals.into());
+
+        // Approvals arrive in separate deploys, and can be taken back
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_APPROVE,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_success()
+        .commit();
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_REVOKE_APPROVAL,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_success()
+        .commit();
+
+        call_stored_contract(
+            &mut builder,
+            USER_2_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_APPROVE,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_success()
+        .commit();
+
+        assert_eq!(
+            query_proposal(&builder, contract_hash, 0),
+            Proposal {
+                target: USER_3_ACCOUNT,
+                amount,
+                approvals: vec![*DEFAULT_ACCOUNT_ADDR, USER_2_ACCOUNT],
+                executed: false,
+            }
+        );
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_EXECUTE,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_success()
+        .commit();
+
+        // Prepare assertions.
+        let target_account = builder
+            .get_account(USER_3_ACCOUNT)
+            .expect("should have created the target account");
+        assert_eq!(
+            builder.get_purse_balance(target_account.main_purse()),
+            amount
+        );
+        assert!(query_proposal(&builder, contract_hash, 0).executed);
+
+        // A proposal is executed only once
+        call_stored_contract(
+            &mut builder,
+            USER_2_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_EXECUTE,
+            runtime_args! { RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_failure();
+
+        assert_reverted_with(&builder, MultisigWalletError::AlreadyExecuted.into());
+    }
+
+    #[test]
+    fn should_revert_multisig_wallet_call_from_non_signer() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        fund_account(&mut builder, USER_1_ACCOUNT);
+
+        let contract_hash = install_multisig_wallet(&mut builder, vec![*DEFAULT_ACCOUNT_ADDR], 1);
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            ENTRY_POINT_PROPOSE_TRANSFER,
+            runtime_args! {
+                RUNTIME_ARG_TARGET => USER_1_ACCOUNT,
+                RUNTIME_ARG_AMOUNT => U512::from(1u64),
+            },
+        )
+        .expect_failure();
+
+        assert_reverted_with(&builder, MultisigWalletError::NotASigner.into());
+    }
+
+    #[test]
+    fn should_revert_multisig_wallet_with_unreachable_threshold() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MULTISIG_WALLET_WASM,
+            runtime_args! {
+                RUNTIME_ARG_SIGNERS => vec![*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT],
+                RUNTIME_ARG_THRESHOLD => 3u8,
+            },
+        )
+        .build();
+
+        builder.exec(install_request).expect_failure();
+
+        assert_reverted_with(&builder, MultisigWalletError::InvalidThreshold.into());
+    }
+
+    #[test]
+    fn should_apply_key_change_after_approvals() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        fund_account(&mut builder, USER_1_ACCOUNT);
+
+        let contract_hash = install_key_change_proposals(
+            &mut builder,
+            BTreeMap::from([(*DEFAULT_ACCOUNT_ADDR, 1), (USER_1_ACCOUNT, 1)]),
+            2,
+        );
+
+        call_stored_contract(
+            &mut builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            contract_hash,
+            key_change::ENTRY_POINT_PROPOSE_ADD_KEY,
+            runtime_args! {
+                key_change::RUNTIME_ARG_ACCOUNT => USER_2_ACCOUNT,
+                key_change::RUNTIME_ARG_WEIGHT => 1u8,
+            },
+        )
+        .expect_success()
+        .commit();
+
+        // The proposer's approval alone does not meet the threshold of 2
+        apply_key_change(&mut builder, 0).expect_failure();
+
+        assert_reverted_with(
+            &builder,
+            KeyChangeProposalsError::InsufficientApprovals.into(),
+        );
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            key_change::ENTRY_POINT_APPROVE,
+            runtime_args! { key_change::RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_success()
+        .commit();
+
+        apply_key_change(&mut builder, 0).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(
+            account.associated_keys().get(&USER_2_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+
+        // The change is recorded like any other key change
+        let record: AuditRecord = query_audit_log(&builder, "0");
+        assert_eq!(record.operation, Operation::AddKey);
+        assert_eq!(record.account_hash, Some(USER_2_ACCOUNT));
+
+        // A proposal is applied once
+        apply_key_change(&mut builder, 0).expect_failure();
+
+        assert_reverted_with(&builder, KeyChangeProposalsError::AlreadyApplied.into());
+    }
+
+    #[test]
+    fn should_only_hand_approved_changes_to_the_owner() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        fund_account(&mut builder, USER_1_ACCOUNT);
+
+        let contract_hash = install_key_change_proposals(
+            &mut builder,
+            BTreeMap::from([(*DEFAULT_ACCOUNT_ADDR, 1), (USER_1_ACCOUNT, 1)]),
+            1,
+        );
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            key_change::ENTRY_POINT_PROPOSE_REMOVE_KEY,
+            runtime_args! { key_change::RUNTIME_ARG_ACCOUNT => USER_2_ACCOUNT },
+        )
+        .expect_success()
+        .commit();
+
+        call_stored_contract(
+            &mut builder,
+            USER_1_ACCOUNT,
+            contract_hash,
+            key_change::ENTRY_POINT_TAKE_APPROVED_CHANGE,
+            runtime_args! { key_change::RUNTIME_ARG_PROPOSAL_ID => 0u64 },
+        )
+        .expect_failure();
+
+        assert_reverted_with(&builder, KeyChangeProposalsError::NotOwner.into());
+
+        // The owner can take it, but the host still rejects removing a key it does not have
+        apply_key_change(&mut builder, 0).expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::MissingKey.into());
+    }
+
+    #[test]
+    fn should_not_install_key_change_proposals_with_unreachable_threshold() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_CHANGE_PROPOSALS_WASM,
+            runtime_args! {
+                key_change::RUNTIME_ARG_SIGNERS => BTreeMap::from([(*DEFAULT_ACCOUNT_ADDR, 1u8)]),
+                key_change::RUNTIME_ARG_APPROVAL_THRESHOLD => 2u8,
+            },
+        )
+        .build();
+
+        builder.exec(install_request).expect_failure();
+
+        assert_reverted_with(&builder, KeyChangeProposalsError::InvalidThreshold.into());
+    }
+
+    #[test]
+    fn should_execute_queued_key_change_after_delay() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let queue_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            QUEUE_KEY_CHANGE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(queue_request).expect_success().commit();
+
+        let execute_request = |block_time| {
+            ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                EXECUTE_KEY_CHANGE_WASM,
+                runtime_args! { RUNTIME_ARG_CHANGE_ID => 0u64 },
+            )
+            .with_block_time(block_time)
+            .build()
+        };
+
+        builder
+            .exec(execute_request(1_000 + MIN_DELAY - 1))
+            .expect_failure();
+
+        assert_reverted_with(&builder, ExecuteKeyChangeError::TooEarly.into());
+
+        builder
+            .exec(execute_request(1_000 + MIN_DELAY))
+            .expect_success()
+            .commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+
+        // An executed change leaves the queue
+        builder
+            .exec(execute_request(1_000 + MIN_DELAY))
+            .expect_failure();
+
+        assert_reverted_with(&builder, ExecuteKeyChangeError::MissingChange.into());
+    }
+
+    #[test]
+    fn should_not_execute_cancelled_key_change() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let queue_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            QUEUE_KEY_CHANGE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => 1u8,
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => 2u8,
+                RUNTIME_ARG_DELAY => 2 * MIN_DELAY,
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(queue_request).expect_success().commit();
+
+        let cancel_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CANCEL_KEY_CHANGE_WASM,
+            runtime_args! { RUNTIME_ARG_CHANGE_ID => 0u64 },
+        )
+        .with_block_time(2_000)
+        .build();
+
+        builder.exec(cancel_request).expect_success().commit();
+
+        let execute_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            EXECUTE_KEY_CHANGE_WASM,
+            runtime_args! { RUNTIME_ARG_CHANGE_ID => 0u64 },
+        )
+        .with_block_time(1_000 + 2 * MIN_DELAY)
+        .build();
+
+        builder.exec(execute_request).expect_failure();
+
+        assert_reverted_with(&builder, ExecuteKeyChangeError::MissingChange.into());
+    }
+
+    #[test]
+    fn should_cancel_key_change_only_with_deployment_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 1u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        let queue_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            QUEUE_KEY_CHANGE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH => USER_2_ACCOUNT,
+            },
+        )
+        .with_block_time(1_000)
+        .build();
+
+        builder.exec(queue_request).expect_success().commit();
+
+        // A single signer below the deployment threshold cannot send the cancelling deploy at all
+        let cancel_args = runtime_args! { RUNTIME_ARG_CHANGE_ID => 0u64 };
+        let cancel_request = multisig_request(
+            &[USER_1_ACCOUNT],
+            CANCEL_KEY_CHANGE_WASM,
+            cancel_args.clone(),
+            2_000,
+        );
+
+        builder.exec(cancel_request).expect_failure();
+
+        assert!(matches!(
+            builder.get_error(),
+            Some(EngineStateError::Exec(
+                execution::Error::DeploymentAuthorizationFailure
+            ))
+        ));
+
+        // Signers meeting the deployment threshold together can cancel without key management weight
+        let cancel_request = multisig_request(
+            &[USER_1_ACCOUNT, USER_2_ACCOUNT],
+            CANCEL_KEY_CHANGE_WASM,
+            cancel_args,
+            2_000,
+        );
+
+        builder.exec(cancel_request).expect_success().commit();
+
+        let execute_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            EXECUTE_KEY_CHANGE_WASM,
+            runtime_args! { RUNTIME_ARG_CHANGE_ID => 0u64 },
+        )
+        .with_block_time(1_000 + MIN_DELAY)
+        .build();
+
+        builder.exec(execute_request).expect_failure();
+
+        assert_reverted_with(&builder, ExecuteKeyChangeError::MissingChange.into());
+    }
+
+    #[test]
+    fn should_not_queue_key_change_with_short_delay() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let queue_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            QUEUE_KEY_CHANGE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_DELAY => MIN_DELAY - 1,
+            },
+        )
+        .build();
+
+        builder.exec(queue_request).expect_failure();
+
+        assert_reverted_with(&builder, QueueKeyChangeError::DelayTooShort.into());
+    }
+
+    #[test]
+    fn should_not_queue_several_key_changes_at_once() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let queue_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            QUEUE_KEY_CHANGE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_REMOVE_ASSOCIATED_ACCOUNT_HASH => USER_2_ACCOUNT,
+            },
+        )
+        .build();
+
+        builder.exec(queue_request).expect_failure();
+
+        assert_reverted_with(&builder, QueueKeyChangeError::InvalidChange.into());
+    }
+
+    #[test]
+    fn should_transfer_above_spending_limit_only_with_key_management_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        let both_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
+        let one_key = [*DEFAULT_ACCOUNT_ADDR];
+        let limit = U512::from(5_000_000_000u64);
+        let amount = U512::from(3_000_000_000u64);
+
+        let set_limit_args = || {
+            runtime_args! {
+                RUNTIME_ARG_SPENDING_LIMIT => limit,
+                RUNTIME_ARG_SPENDING_PERIOD => 10_000u64,
+            }
+        };
+
+        // Setting the limit needs key management weight
+        builder
+            .exec(multisig_request(
+                &one_key,
+                SET_SPENDING_LIMIT_WASM,
+                set_limit_args(),
+                1_000,
+            ))
+            .expect_failure();
+
+        assert_reverted_with(&builder, SetSpendingLimitError::PermissionDenied.into());
+
+        builder
+            .exec(multisig_request(
+                &both_keys,
+                SET_SPENDING_LIMIT_WASM,
+                set_limit_args(),
+                1_000,
+            ))
+            .expect_success()
+            .commit();
+
+        let transfer_args = || {
+            runtime_args! {
+                RUNTIME_ARG_TARGET => USER_3_ACCOUNT,
+                RUNTIME_ARG_AMOUNT => amount,
+                RUNTIME_ARG_TRANSFER_ID => 7u64,
+            }
+        };
+
+        // Under the limit, one key is enough
+        builder
+            .exec(multisig_request(
+                &one_key,
+                TRANSFER_WASM,
+                transfer_args(),
+                2_000,
+            ))
+            .expect_success()
+            .commit();
+
+        // The second transfer of the period would exceed the limit
+        builder
+            .exec(multisig_request(
+                &one_key,
+                TRANSFER_WASM,
+                transfer_args(),
+                3_000,
+            ))
+            .expect_failure();
+
+        assert_reverted_with(&builder, TransferError::LimitExceeded.into());
+
+        builder
+            .exec(multisig_request(
+                &both_keys,
+                TRANSFER_WASM,
+                transfer_args(),
+                3_000,
+            ))
+            .expect_success()
+            .commit();
+
+        // A new period resets the limit
+        builder
+            .exec(multisig_request(
+                &one_key,
+                TRANSFER_WASM,
+                transfer_args(),
+                11_000,
+            ))
+            .expect_success()
+            .commit();
+
+        let target = builder
+            .get_account(USER_3_ACCOUNT)
+            .expect("should have the target account");
+        assert_eq!(builder.get_purse_balance(target.main_purse()), amount * 3);
+
+        // The probe key used for the weight check is never left on the account
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(account.associated_keys().len(), 2);
+    }
+
+    #[test]
+    fn should_not_transfer_without_spending_limit_or_key_management_weight() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(1),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        // Without a spending limit, every transfer needs key management weight
+        builder
+            .exec(multisig_request(
+                &[*DEFAULT_ACCOUNT_ADDR],
+                TRANSFER_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_TARGET => USER_3_ACCOUNT,
+                    RUNTIME_ARG_AMOUNT => U512::from(3_000_000_000u64),
+                },
+                1_000,
+            ))
+            .expect_failure();
+
+        assert_reverted_with(&builder, TransferError::LimitExceeded.into());
+    }
+
+    #[test]
+    fn should_delegate_undelegate_and_redelegate_from_multisig_account() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let validator_1 = DEFAULT_PROPOSER_PUBLIC_KEY.clone();
+        let validator_2 = PublicKey::from(
+            &SecretKey::ed25519_from_bytes([6u8; 32]).expect("should create ed25519 key"),
+        );
+        fund_account(&mut builder, validator_2.to_account_hash());
+        add_validator_bid(&mut builder, &validator_1);
+        add_validator_bid(&mut builder, &validator_2);
+
+        // Deploys of the treasury account need both of its keys
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            ADD_ACCOUNT_WASM,
+            runtime_args! {
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_threshold_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            UPDATE_THRESHOLDS_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(2),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_threshold_request)
+            .expect_success()
+            .commit();
+
+        let both_keys = [*DEFAULT_ACCOUNT_ADDR, USER_1_ACCOUNT];
+        let delegated = U512::from(2_000_000_000_000u64);
+        let delegate_args = || {
+            runtime_args! {
+                RUNTIME_ARG_DELEGATOR => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+                RUNTIME_ARG_VALIDATOR => validator_1.clone(),
+                RUNTIME_ARG_DELEGATION_AMOUNT => delegated,
+            }
+        };
+
+        builder
+            .exec(multisig_request(
+                &[*DEFAULT_ACCOUNT_ADDR],
+                DELEGATE_WASM,
+                delegate_args(),
+                0,
+            ))
+            .expect_failure();
 
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        builder
+            .exec(multisig_request(
+                &both_keys,
+                DELEGATE_WASM,
+                delegate_args(),
+                0,
+            ))
+            .expect_success()
+            .commit();
 
-        // This request should succeed as DEFAULT_ACCOUNT_ADDR has weight 3, greater than the deployment threshold
-        builder.exec(deploy_request).expect_success().commit();
+        let bids = builder.get_bids();
+        let delegator = bids
+            .get(&validator_1)
+            .and_then(|bid| bid.delegators().get(&*DEFAULT_ACCOUNT_PUBLIC_KEY))
+            .expect("should have delegated");
+        assert_eq!(*delegator.staked_amount(), delegated);
 
-        // Step 7: Send a multi-signature deploy from an associated key
+        let undelegated = U512::from(200_000_000_000u64);
+        builder
+            .exec(multisig_request(
+                &both_keys,
+                UNDELEGATE_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DELEGATOR => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+                    RUNTIME_ARG_VALIDATOR => validator_1.clone(),
+                    RUNTIME_ARG_DELEGATION_AMOUNT => undelegated,
+                },
+                0,
+            ))
+            .expect_success()
+            .commit();
 
-        // This deploy request should fail as USER_1_ACCOUNT has weight 1, below deployment threshold of 2
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT
-            })
-            .with_session_code(session_code.clone(), session_args.clone())
-            .with_authorization_keys(&[USER_1_ACCOUNT])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .build();
+        // Redelegations are held to the minimum delegation amount
+        let redelegated = U512::from(600_000_000_000u64);
+        builder
+            .exec(multisig_request(
+                &both_keys,
+                REDELEGATE_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_DELEGATOR => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+                    RUNTIME_ARG_VALIDATOR => validator_1.clone(),
+                    RUNTIME_ARG_NEW_VALIDATOR => validator_2.clone(),
+                    RUNTIME_ARG_DELEGATION_AMOUNT => redelegated,
+                },
+                0,
+            ))
+            .expect_success()
+            .commit();
 
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        let unbonding_purses = builder
+            .get_unbonds()
+            .into_values()
+            .flatten()
+            .filter(|purse| *purse.unbonder_public_key() == *DEFAULT_ACCOUNT_PUBLIC_KEY)
+            .map(|purse| (*purse.amount(), purse.new_validator().clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unbonding_purses,
+            vec![(undelegated, None), (redelegated, Some(validator_2))]
+        );
 
-        builder.exec(deploy_request).expect_failure();
+        let bids = builder.get_bids();
+        let delegator = bids
+            .get(&validator_1)
+            .and_then(|bid| bid.delegators().get(&*DEFAULT_ACCOUNT_PUBLIC_KEY))
+            .expect("should still be delegated");
+        assert_eq!(
+            *delegator.staked_amount(),
+            delegated - undelegated - redelegated
+        );
+    }
 
-        // This deploy request should succeed as USER_1_ACCOUNT + USER_2_ACCOUNT have weight 2, equal deployment threshold of 2
+    #[test]
+    fn should_not_delegate_for_another_public_key() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
 
-        let deploy_item = DeployItemBuilder::new()
-            .with_empty_payment_bytes(runtime_args! {
-                ARG_AMOUNT => *DEFAULT_PAYMENT
-            })
-            .with_session_code(session_code, session_args)
-            .with_authorization_keys(&[USER_1_ACCOUNT, USER_2_ACCOUNT])
-            .with_address(*DEFAULT_ACCOUNT_ADDR)
-            .build();
+        add_validator_bid(&mut builder, &DEFAULT_PROPOSER_PUBLIC_KEY);
 
-        let deploy_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        let delegate_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            DELEGATE_WASM,
+            runtime_args! {
+                RUNTIME_ARG_DELEGATOR => DEFAULT_PROPOSER_PUBLIC_KEY.clone(),
+                RUNTIME_ARG_VALIDATOR => DEFAULT_PROPOSER_PUBLIC_KEY.clone(),
+                RUNTIME_ARG_DELEGATION_AMOUNT => U512::from(1_000_000_000_000u64),
+            },
+        )
+        .build();
 
-        builder.exec(deploy_request).expect_success().commit();
+        builder.exec(delegate_request).expect_failure();
+
+        assert_reverted_with(&builder, DelegateError::InvalidDelegator.into());
+    }
+
+    #[test]
+    fn should_run_a_single_operation_through_key_manager() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let add_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_MANAGER_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OPERATION => OPERATION_ADD,
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY => Key::from(USER_1_ACCOUNT),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_WEIGHT => Weight::new(1),
+                RUNTIME_ARG_NEW_ASSOCIATED_KEY_LABEL => "laptop",
+            },
+        )
+        .build();
+
+        builder.exec(add_key_request).expect_success().commit();
+
+        let update_weight_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_MANAGER_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OPERATION => OPERATION_UPDATE_WEIGHT,
+                RUNTIME_ARG_ASSOCIATED_ACCOUNT_HASH => USER_1_ACCOUNT,
+                RUNTIME_ARG_NEW_KEY_WEIGHT => Weight::new(3),
+            },
+        )
+        .build();
+
+        builder
+            .exec(update_weight_request)
+            .expect_success()
+            .commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(3))
+        );
+        assert_eq!(
+            list_key_labels(&builder).get(&USER_1_ACCOUNT),
+            Some(&Some("laptop".to_string()))
+        );
+
+        // A failed operation reports the error of the single-purpose contract.
+        let remove_key_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_MANAGER_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OPERATION => OPERATION_REMOVE,
+                RUNTIME_ARG_REMOVE_ASSOCIATED_KEY => Key::from(USER_2_ACCOUNT),
+            },
+        )
+        .build();
+
+        builder.exec(remove_key_request).expect_failure();
+        assert_reverted_with(&builder, RemoveAccountError::MissingKey.into());
+    }
+
+    #[test]
+    fn should_apply_a_list_of_operations_in_order() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        // The thresholds are only reachable once both keys are added, and the rotation needs the
+        // key added before it.
+        let operations = vec![
+            KeyOperation::Add {
+                account: USER_1_ACCOUNT,
+                weight: 1,
+            },
+            KeyOperation::Add {
+                account: USER_2_ACCOUNT,
+                weight: 1,
+            },
+            KeyOperation::SetThresholds {
+                deployment: Some(2),
+                key_management: Some(3),
+            },
+            KeyOperation::Rotate {
+                old_account: USER_2_ACCOUNT,
+                new_account: USER_3_ACCOUNT,
+                weight: None,
+            },
+            KeyOperation::UpdateWeight {
+                account: USER_1_ACCOUNT,
+                weight: 2,
+            },
+        ];
+        let key_manager_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_MANAGER_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OPERATIONS => operations,
+            },
+        )
+        .build();
+
+        builder.exec(key_manager_request).expect_success().commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(
+            account.associated_keys().get(&USER_1_ACCOUNT),
+            Some(&Weight::new(2))
+        );
+        assert_eq!(account.associated_keys().get(&USER_2_ACCOUNT), None);
+        assert_eq!(
+            account.associated_keys().get(&USER_3_ACCOUNT),
+            Some(&Weight::new(1))
+        );
+        assert_eq!(account.action_thresholds().deployment(), &Weight::new(2));
+        assert_eq!(
+            account.action_thresholds().key_management(),
+            &Weight::new(3)
+        );
+    }
+
+    #[test]
+    fn should_serialize_key_operations_as_typed_tuples() {
+        type KeyOperationTuple = (
+            u8,
+            (Option<AccountHash>, Option<AccountHash>),
+            (Option<u8>, Option<u8>),
+        );
+
+        let operations = [
+            (
+                KeyOperation::Add {
+                    account: USER_1_ACCOUNT,
+                    weight: 1,
+                },
+                (0, (Some(USER_1_ACCOUNT), None), (Some(1), None)),
+            ),
+            (
+                KeyOperation::Remove {
+                    account: USER_1_ACCOUNT,
+                },
+                (1, (Some(USER_1_ACCOUNT), None), (None, None)),
+            ),
+            (
+                KeyOperation::UpdateWeight {
+                    account: USER_1_ACCOUNT,
+                    weight: 2,
+                },
+                (2, (Some(USER_1_ACCOUNT), None), (Some(2), None)),
+            ),
+            (
+                KeyOperation::SetThresholds {
+                    deployment: None,
+                    key_management: Some(3),
+                },
+                (3, (None, None), (None, Some(3))),
+            ),
+            (
+                KeyOperation::Rotate {
+                    old_account: USER_1_ACCOUNT,
+                    new_account: USER_2_ACCOUNT,
+                    weight: None,
+                },
+                (
+                    4,
+                    (Some(USER_1_ACCOUNT), Some(USER_2_ACCOUNT)),
+                    (None, None),
+                ),
+            ),
+        ];
+
+        for (operation, tuple) in operations {
+            let cl_value = CLValue::from_t(operation).expect("should serialize");
+            assert_eq!(cl_value.clone().into_t::<KeyOperationTuple>(), Ok(tuple));
+            assert_eq!(
+                CLValue::from_t(tuple)
+                    .expect("should serialize")
+                    .into_t::<KeyOperation>(),
+                Ok(operation)
+            );
+        }
+
+        // A tuple that fits no operation is refused
+        let invalid: KeyOperationTuple = (0, (None, None), (Some(1), None));
+        assert!(CLValue::from_t(invalid)
+            .expect("should serialize")
+            .into_t::<KeyOperation>()
+            .is_err());
+    }
+
+    #[test]
+    fn should_revert_whole_list_when_one_operation_fails() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let operations = vec![
+            KeyOperation::Add {
+                account: USER_1_ACCOUNT,
+                weight: 1,
+            },
+            KeyOperation::Remove {
+                account: USER_2_ACCOUNT,
+            },
+        ];
+        let key_manager_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            KEY_MANAGER_WASM,
+            runtime_args! {
+                RUNTIME_ARG_OPERATIONS => operations,
+            },
+        )
+        .build();
+
+        builder.exec(key_manager_request).expect_failure();
+        assert_reverted_with(&builder, RemoveAccountError::MissingKey.into());
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(account.associated_keys().get(&USER_1_ACCOUNT), None);
+    }
+
+    #[test]
+    fn should_leave_reachability_to_the_host_after_changes_outside_the_contracts() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let mut associated_keys = BTreeMap::new();
+        associated_keys.insert(Key::from(USER_1_ACCOUNT), 2u8);
+        associated_keys.insert(Key::from(USER_2_ACCOUNT), 1u8);
+
+        let configure_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONFIGURE_MULTISIG_WASM,
+            runtime_args! {
+                RUNTIME_ARG_PRIMARY_KEY_WEIGHT => Weight::new(3),
+                RUNTIME_ARG_ASSOCIATED_KEYS => associated_keys,
+                RUNTIME_ARG_DEPLOYMENT_THRESHOLD => Weight::new(2),
+                RUNTIME_ARG_KEY_MANAGEMENT_THRESHOLD => Weight::new(5),
+            },
+        )
+        .build();
+
+        builder.exec(configure_request).expect_success().commit();
+
+        // The audit log still records keys weighing 6 against a key management threshold of 5, but
+        // the account gains a key weighing 3 that it does not know about
+        change_account_outside_contracts(&mut builder, |account| {
+            account
+                .add_associated_key(USER_3_ACCOUNT, Weight::new(3))
+                .expect("should add the key");
+        });
+
+        let authorization_keys = [*DEFAULT_ACCOUNT_ADDR, USER_3_ACCOUNT];
+        let key_manager_request = |operation| {
+            multisig_request(
+                &authorization_keys,
+                KEY_MANAGER_WASM,
+                runtime_args! {
+                    RUNTIME_ARG_OPERATIONS => vec![operation],
+                },
+                0,
+            )
+        };
+
+        // Removing the key weighing 2 leaves 4 of the recorded weight, but 7 of the actual weight
+        builder
+            .exec(key_manager_request(KeyOperation::Remove {
+                account: USER_1_ACCOUNT,
+            }))
+            .expect_success()
+            .commit();
+
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("Should be an account.");
+        assert_eq!(account.associated_keys().get(&USER_1_ACCOUNT), None);
+        assert_eq!(
+            account.associated_keys().get(&USER_3_ACCOUNT),
+            Some(&Weight::new(3))
+        );
+
+        // A change the host refuses still fails with the user error of the operation
+        builder
+            .exec(key_manager_request(KeyOperation::Remove {
+                account: USER_3_ACCOUNT,
+            }))
+            .expect_failure();
+
+        assert_reverted_with(&builder, RemoveAccountError::LockoutRisk.into());
+    }
+
+    #[test]
+    fn should_revert_key_manager_without_a_valid_operation() {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let cases = vec![
+            (RuntimeArgs::new(), KeyManagerError::MissingOperation),
+            (
+                runtime_args! {
+                    RUNTIME_ARG_OPERATION => OPERATION_REMOVE,
+                    RUNTIME_ARG_OPERATIONS => Vec::<KeyOperation>::new(),
+                },
+                KeyManagerError::ConflictingOperations,
+            ),
+            (
+                runtime_args! {
+                    RUNTIME_ARG_OPERATION => "freeze",
+                },
+                KeyManagerError::UnknownOperation,
+            ),
+            (
+                runtime_args! {
+                    RUNTIME_ARG_OPERATIONS => Vec::<KeyOperation>::new(),
+                },
+                KeyManagerError::EmptyOperations,
+            ),
+        ];
+
+        for (session_args, expected_error) in cases {
+            let key_manager_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                KEY_MANAGER_WASM,
+                session_args,
+            )
+            .build();
+
+            builder.exec(key_manager_request).expect_failure();
+            assert_reverted_with(&builder, expected_error.into());
+        }
     }
 }
 